@@ -0,0 +1,53 @@
+//! Benchmarks of `place` on large inputs.
+//!
+//! Run with `cargo bench -p place_macro_core`.
+
+use std::{hint::black_box, time::Instant};
+
+use place_macro_core::{place, place_into};
+use proc_macro2::TokenStream;
+
+/// Number of repetitions of the input chunk.
+const SIZE: usize = 10_000;
+/// Number of runs of each benchmark.
+const RUNS: u32 = 10;
+
+const CHUNK: &str = "
+    let __identifier__(a b) = __string__(c d);
+    const __identifier__(X Y): &str = __stringify__(1 + 2);
+    fn __identifier__(f __tail__(x y))() -> u32 {
+        __ignore__ __ref__(v) __set__(v (5 + __head__(1 2 3))) __ref__(v) * 2
+    }
+    struct A { a: u8, b: (u16, [u32; 4]), c: Vec<u64> }
+";
+
+/// Input with many identifiers and few macros.
+const IDENTS: &str = "
+    let __value__ = __data__.iter().map(|__x__| __x__ * factor).sum::<u32>();
+    __ToCase__(some_ident) __TO_CASE__(other_ident) __ignore__(a b c)
+";
+
+fn bench(name: &str, input: &TokenStream, f: impl Fn(TokenStream)) {
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        f(black_box(input.clone()));
+    }
+    println!("{name:<12} {:?}/iter", start.elapsed() / RUNS);
+}
+
+fn main() {
+    let input: TokenStream = CHUNK.repeat(SIZE).parse().unwrap();
+    let idents: TokenStream = IDENTS.repeat(SIZE).parse().unwrap();
+
+    bench("place", &input, |i| {
+        black_box(place(i));
+    });
+    bench("place_into", &input, |i| {
+        let mut out = TokenStream::new();
+        place_into(i, &mut out).unwrap();
+        black_box(out);
+    });
+    bench("idents", &idents, |i| {
+        black_box(place(i));
+    });
+}
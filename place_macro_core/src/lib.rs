@@ -1,4 +1,8 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
 
 use convert_case::{Case, Casing};
 use proc_macro2::{
@@ -10,7 +14,7 @@ macro_rules! check_comma {
     ($stream:expr, $pos:expr) => {
         if let Some(tree) = $stream.next() {
             if !is_comma(&tree) {
-                return error_at(tree.span(), "Expected comma.");
+                return error_range($pos, tree.span(), "Expected comma.");
             }
         } else {
             return error_at($pos, "Expected more arguments");
@@ -24,11 +28,54 @@ fn spanned(mut tree: TokenTree, span: Span) -> TokenTree {
 }
 
 fn error_at<S>(span: Span, msg: S) -> TokenStream
+where
+    S: AsRef<str>,
+{
+    compile_error_tokens(span, span, msg)
+}
+
+/// Creates error that spans from `start` to `end`. This is used when the error
+/// is caused by the token at `end` but is related to the macro at `start`.
+///
+/// Without the feature `rich-errors` the error is only at `end`.
+#[cfg(feature = "rich-errors")]
+fn error_range<S>(start: Span, end: Span, msg: S) -> TokenStream
+where
+    S: AsRef<str>,
+{
+    compile_error_tokens(start, end, msg)
+}
+
+/// Creates error that spans from `start` to `end`. This is used when the error
+/// is caused by the token at `end` but is related to the macro at `start`.
+///
+/// Without the feature `rich-errors` the error is only at `end`.
+#[cfg(not(feature = "rich-errors"))]
+fn error_range<S>(_start: Span, end: Span, msg: S) -> TokenStream
+where
+    S: AsRef<str>,
+{
+    error_at(end, msg)
+}
+
+/// Creates the tokens `::core::compile_error!("msg")`. The compiler will
+/// report the error from the start of the first token to the end of the last
+/// token, so it is possible to create error that covers multiple tokens on
+/// stable.
+///
+/// The path is fully qualified so that the error isn't passed to the macro
+/// `compile_error` from this crate when it is imported.
+fn compile_error_tokens<S>(start: Span, end: Span, msg: S) -> TokenStream
 where
     S: AsRef<str>,
 {
     [
-        TokenTree::Ident(Ident::new("compile_error", span)),
+        spanned(TokenTree::Punct(Punct::new(':', Spacing::Joint)), start),
+        spanned(TokenTree::Punct(Punct::new(':', Spacing::Alone)), start),
+        TokenTree::Ident(Ident::new("core", start)),
+        spanned(TokenTree::Punct(Punct::new(':', Spacing::Joint)), start),
+        spanned(TokenTree::Punct(Punct::new(':', Spacing::Alone)), start),
+        TokenTree::Ident(Ident::new("compile_error", start)),
         TokenTree::Punct(Punct::new('!', Spacing::Alone)),
         spanned(
             TokenTree::Group(Group::new(
@@ -37,15 +84,84 @@ where
                     .into_iter()
                     .collect(),
             )),
-            span,
+            end,
         ),
     ]
     .into_iter()
     .collect()
 }
 
+/// Gets exactly `N` comma separated arguments, each argument is single token.
+/// There may be trailing comma.
+fn get_args<const N: usize>(
+    input: TokenStream,
+    pos: Span,
+) -> Result<[TokenTree; N], TokenStream> {
+    let mut i = input.into_iter();
+    let mut res = Vec::with_capacity(N);
+    let missing = |got: usize| {
+        error_at(pos, format!("Expected {N} arguments, got {got}"))
+    };
+
+    while res.len() < N {
+        if !res.is_empty() {
+            match i.next() {
+                Some(t) if is_comma(&t) => {}
+                Some(t) => {
+                    return Err(error_range(pos, t.span(), "Expected comma."))
+                }
+                None => return Err(missing(res.len())),
+            }
+        }
+        match i.next() {
+            Some(t) => res.push(t),
+            None => return Err(missing(res.len())),
+        }
+    }
+
+    if let Some(n) = i.next() {
+        if !is_comma(&n) {
+            return Err(error_range(
+                pos,
+                n.span(),
+                "Unexpected token in macro invocation",
+            ));
+        }
+        if let Some(t) = i.next() {
+            return Err(error_range(
+                pos,
+                t.span(),
+                format!("Macro takes only {N} arguments"),
+            ));
+        }
+    }
+
+    Ok(res.try_into().unwrap())
+}
+
+/// Splits the input into comma separated arguments, each argument may have
+/// any number of tokens. There may be trailing comma.
+fn split_args(input: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut res = vec![vec![]];
+    for t in input {
+        if is_comma(&t) {
+            res.push(vec![]);
+        } else {
+            res.last_mut().unwrap().push(t);
+        }
+    }
+    if res.last().is_some_and(|a| a.is_empty()) {
+        res.pop();
+    }
+    res
+}
+
 fn is_comma(tree: &TokenTree) -> bool {
-    matches!(tree, TokenTree::Punct(p) if p.as_char() == ',')
+    is_punct(tree, ',')
+}
+
+fn is_punct(tree: &TokenTree, c: char) -> bool {
+    matches!(tree, TokenTree::Punct(p) if p.as_char() == c)
 }
 
 pub fn ignore(_input: TokenStream) -> TokenStream {
@@ -69,7 +185,15 @@ pub fn dollar(input: TokenStream) -> TokenStream {
 }
 
 pub fn string(input: TokenStream) -> TokenStream {
-    let res = token_concat(input);
+    let res = token_concat(input, false);
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(res.as_str()))]);
+    r
+}
+
+pub fn string_typed(input: TokenStream) -> TokenStream {
+    let res = token_concat(input, true);
 
     let mut r = TokenStream::new();
     r.extend([TokenTree::Literal(Literal::string(res.as_str()))]);
@@ -130,17 +254,124 @@ pub fn reverse(input: TokenStream) -> TokenStream {
     res
 }
 
+/// Strict and reserved keywords in all editions.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "become", "box", "break", "const", "continue", "crate",
+    "do", "else", "enum", "extern", "false", "final", "fn", "for", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut",
+    "override", "priv", "pub", "ref", "return", "self", "Self", "static",
+    "struct", "super", "trait", "true", "type", "typeof", "unsafe", "unsized",
+    "use", "virtual", "where", "while", "yield",
+];
+
+/// Strict and reserved keywords only in some editions.
+const EDITION_KEYWORDS: &[&str] = &["async", "await", "dyn", "gen", "try"];
+
+/// Keywords that cannot be raw identifiers.
+const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Creates non raw identifier from the string. Fails if the string is not
+/// valid identifier or if it is keyword that can be raw identifier. Keywords
+/// that cannot be raw identifiers (e.g. `self`) are allowed.
+fn checked_ident(name: &str, span: Span) -> Result<Ident, TokenStream> {
+    if !is_ident(name) {
+        return Err(error_at(
+            span,
+            format!("'{name}' is not valid identifier"),
+        ));
+    }
+    if KEYWORDS.contains(&name) && !NON_RAW_KEYWORDS.contains(&name) {
+        return Err(error_at(
+            span,
+            format!(
+                "Identifier would be keyword `{name}`, use `raw_identifier` \
+                to create raw identifier `r#{name}`"
+            ),
+        ));
+    }
+
+    Ok(Ident::new(name, Span::call_site()))
+}
+
 pub fn identifier(input: TokenStream) -> TokenStream {
-    let res = token_concat(input);
+    let res = token_concat(input, false);
+
+    let ident = match checked_ident(&res, Span::call_site()) {
+        Ok(i) => i,
+        Err(e) => return e,
+    };
 
     let mut r = TokenStream::new();
-    r.extend([TokenTree::Ident(Ident::new(&res, Span::call_site()))]);
+    r.extend([TokenTree::Ident(ident)]);
+    r
+}
+
+pub fn raw_identifier(input: TokenStream) -> TokenStream {
+    let res = token_concat(input, false);
+
+    if !is_ident(&res) {
+        return error_at(
+            Span::call_site(),
+            format!("'{res}' is not valid identifier"),
+        );
+    }
+    if NON_RAW_KEYWORDS.contains(&res.as_str()) {
+        return error_at(
+            Span::call_site(),
+            format!("`{res}` cannot be raw identifier"),
+        );
+    }
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Ident(Ident::new_raw(&res, Span::call_site()))]);
     r
 }
 
 pub fn stringify(input: TokenStream) -> TokenStream {
+    let mut i = input.clone().into_iter();
+    let flag = match (i.next(), i.next()) {
+        (Some(f), Some(c)) if is_comma(&c) => get_str_lit(f),
+        _ => None,
+    };
+
+    let s = match flag.as_deref() {
+        Some("compact") => compact_string(i.collect()),
+        Some("spaced") => i.collect::<TokenStream>().to_string(),
+        _ => input.to_string(),
+    };
+
     let mut res = TokenStream::new();
-    res.extend([TokenTree::Literal(Literal::string(&input.to_string()))]);
+    res.extend([TokenTree::Literal(Literal::string(&s))]);
+    res
+}
+
+/// Stringifies the tokens so that there is space only between two
+/// identifiers or literals.
+fn compact_string(input: TokenStream) -> String {
+    let mut res = String::new();
+    let mut prev_word = false;
+    for t in input {
+        let word = matches!(t, TokenTree::Ident(_) | TokenTree::Literal(_));
+        if word && prev_word {
+            res.push(' ');
+        }
+        prev_word = word;
+
+        match t {
+            TokenTree::Group(g) => {
+                let (open, close) = match g.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::None => ("", ""),
+                };
+                res += open;
+                res += &compact_string(g.stream());
+                res += close;
+            }
+            t => res += &t.to_string(),
+        }
+    }
     res
 }
 
@@ -158,10 +389,18 @@ pub fn replace_newline(input: TokenStream, pos: Span) -> TokenStream {
     if let Some(n) = i.next() {
         if is_comma(&n) {
             if let Some(t) = i.next() {
-                return error_at(t.span(), "Macro takes only 2 arguments");
+                return error_range(
+                    pos,
+                    t.span(),
+                    "Macro takes only 2 arguments",
+                );
             }
         } else {
-            return error_at(n.span(), "Unexpected token in macro invocation");
+            return error_range(
+                pos,
+                n.span(),
+                "Unexpected token in macro invocation",
+            );
         }
     }
 
@@ -195,6 +434,24 @@ pub fn replace_newline(input: TokenStream, pos: Span) -> TokenStream {
     r
 }
 
+/// Replaces in string literal.
+///
+/// # Errors
+/// Errors caused by unexpected token are at the token. With the feature
+/// `rich-errors` they span from the macro to the token.
+/// ```
+/// use place_macro_core::place;
+/// use proc_macro2::TokenStream;
+///
+/// let input: TokenStream =
+///     r#"__str_replace__("a", "b", "c" "d")"#.parse().unwrap();
+/// let res: Vec<_> = place(input).into_iter().collect();
+///
+/// // the error is caused by the literal `"d"`
+/// assert_eq!(res[2].span().start().column, 30);
+/// let start = if cfg!(feature = "rich-errors") { 0 } else { 30 };
+/// assert_eq!(res[0].span().start().column, start);
+/// ```
 pub fn str_replace(input: TokenStream, pos: Span) -> TokenStream {
     let mut i = input.into_iter();
     let s = match i.next() {
@@ -214,10 +471,18 @@ pub fn str_replace(input: TokenStream, pos: Span) -> TokenStream {
     if let Some(n) = i.next() {
         if is_comma(&n) {
             if let Some(t) = i.next() {
-                return error_at(t.span(), "Macro takes only 3 arguments");
+                return error_range(
+                    pos,
+                    t.span(),
+                    "Macro takes only 3 arguments",
+                );
             }
         } else {
-            return error_at(n.span(), "Unexpected token in macro invocation");
+            return error_range(
+                pos,
+                n.span(),
+                "Unexpected token in macro invocation",
+            );
         }
     }
 
@@ -241,57 +506,92 @@ pub fn str_replace(input: TokenStream, pos: Span) -> TokenStream {
     r
 }
 
-pub fn to_case(input: TokenStream, pos: Span) -> TokenStream {
-    let mut i = input.into_iter();
+/// Case specifiers of `to_case` and the cases to which they convert. The
+/// specifiers that are valid identifiers may be also used as name of the macro
+/// in `place` (e.g. `__ToCase__`).
+const CASES: &[(&str, Case)] = &[
+    ("TOCASE", Case::UpperFlat),
+    ("tocase", Case::Flat),
+    ("toCase", Case::Camel),
+    ("ToCase", Case::Pascal),
+    ("to_case", Case::Snake),
+    ("TO_CASE", Case::UpperSnake),
+    ("tOcAsE", Case::Alternating),
+    ("to-case", Case::Kebab),
+    ("To-Case", Case::Train),
+];
 
-    let dst = match i.next() {
-        Some(s) => s,
-        None => return error_at(pos, "Expected 2 arguments."),
-    };
-    check_comma!(i, pos);
-    let src = match i.next() {
-        Some(s) => s,
-        None => return error_at(pos, "Expected 2 arguments"),
+/// Checks whether the string is valid identifier.
+fn is_ident(s: &str) -> bool {
+    let mut c = s.chars();
+    c.next()
+        .is_some_and(|c| c == '_' || unicode_ident::is_xid_start(c))
+        && c.all(unicode_ident::is_xid_continue)
+        && s != "_"
+}
+
+/// Gets the case represented by the case specifier.
+fn get_case(spec: &str) -> Option<Case> {
+    CASES.iter().find(|(s, _)| *s == spec).map(|(_, c)| *c)
+}
+
+pub fn to_case(input: TokenStream, pos: Span) -> TokenStream {
+    let [dst, src] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
     };
-    if let Some(n) = i.next() {
-        if is_comma(&n) {
-            if let Some(t) = i.next() {
-                return error_at(t.span(), "Macro takes only 2 arguments");
-            }
-        } else {
-            return error_at(n.span(), "Unexpected token in macro invocation");
+
+    match get_str_lit(dst.clone()).as_deref().and_then(get_case) {
+        Some(case) => convert_case(case, src),
+        None => {
+            error_at(dst.span(), "Expected case specifier (e.g. \"ToCase\")")
         }
     }
+}
 
-    let dst = match get_str_lit(dst.clone()) {
-        Some(s) => s,
-        None => return error_at(dst.span(), "Expected string literal"),
-    };
-    let src = if let TokenTree::Ident(l) = src {
-        l.to_string()
-    } else {
-        return error_at(src.span(), "Expected identifier");
+/// Converts the identifier to the given case. Kebab and train case results
+/// are not valid identifiers, so they are string literals. Words in
+/// alternating case are not separated.
+fn convert_case(case: Case, src: TokenTree) -> TokenStream {
+    let span = src.span();
+    let src = match src {
+        TokenTree::Ident(i) => i.to_string(),
+        TokenTree::Literal(l) => match get_str_lit(TokenTree::Literal(l)) {
+            Some(s) => s.into_owned(),
+            None => {
+                return error_at(span, "Expected identifier or string literal")
+            }
+        },
+        TokenTree::Group(g) if g.delimiter() == Delimiter::None => {
+            let mut i = g.stream().into_iter();
+            match (i.next(), i.next()) {
+                (Some(t), None) => return convert_case(case, t),
+                _ => return error_at(g.span(), "Expected identifier"),
+            }
+        }
+        t => return error_at(t.span(), "Expected identifier"),
     };
 
-    let s = get_case(&dst, &src);
-    let mut res = TokenStream::new();
-    res.extend([TokenTree::Ident(Ident::new(&s, Span::call_site()))]);
-    res
-}
-
-fn get_case(spec: &str, i: &str) -> String {
-    match spec {
-        "TOCASE" => i.to_case(Case::UpperFlat),
-        "tocase" => i.to_case(Case::Flat),
-        "toCase" => i.to_case(Case::Camel),
-        "ToCase" => i.to_case(Case::Pascal),
-        "to_case" => i.to_case(Case::Snake),
-        "TO_CASE" => i.to_case(Case::UpperSnake),
-        _ => panic!("Unknown case specifier: '{spec}'"),
+    let mut s = src.to_case(case);
+    if case == Case::Alternating {
+        s.retain(|c| c != ' ');
     }
+    let res = match case {
+        Case::Kebab | Case::Train => TokenTree::Literal(Literal::string(&s)),
+        _ if !is_ident(&s) => {
+            return error_at(span, format!("'{s}' is not valid identifier"))
+        }
+        _ => TokenTree::Ident(Ident::new(&s, Span::call_site())),
+    };
+    let mut r = TokenStream::new();
+    r.extend([res]);
+    r
 }
 
-fn token_concat(input: TokenStream) -> String {
+/// Concats the tokens into string. If `typed` is true, numeric literals are
+/// used as they are written (including their suffix), otherwise their value
+/// is used.
+fn token_concat(input: TokenStream, typed: bool) -> String {
     let mut input = vec![input.into_iter()];
     let mut res = String::new();
 
@@ -302,6 +602,10 @@ fn token_concat(input: TokenStream) -> String {
                 TokenTree::Ident(i) => res += &i.to_string(),
                 TokenTree::Punct(_) => {}
                 TokenTree::Literal(l) => match litrs::Literal::from(l) {
+                    litrs::Literal::Integer(v) if typed => {
+                        res += v.raw_input()
+                    }
+                    litrs::Literal::Float(v) if typed => res += v.raw_input(),
                     litrs::Literal::Bool(v) => res += &v.value().to_string(),
                     litrs::Literal::Integer(v) => {
                         if let Some(v) = v.value::<u128>() {
@@ -346,166 +650,3650 @@ fn get_str_lit<'a>(tt: TokenTree) -> Option<Cow<'a, str>> {
     }
 }
 
-pub fn place(input: TokenStream) -> TokenStream {
-    let mut input: Vec<(_, Option<Macro>, _)> =
-        vec![(input.into_iter(), None, Delimiter::None)];
-    let mut res = vec![TokenStream::new()];
+pub fn with_span_of(input: TokenStream, pos: Span) -> TokenStream {
+    let [donor, body] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
 
-    while let Some((i, m, d)) = input.last_mut() {
-        let t = match (i.next(), m) {
-            (Some(t), _) => t,
-            (_, m) => {
-                if let Some(m) = m {
-                    let t = res.pop().expect("1");
-                    res.last_mut().expect("2").extend(m.invoke(t));
-                } else if res.len() != 1 {
-                    let t = res.pop().expect("3");
-                    res.last_mut()
-                        .expect("4")
-                        .extend([TokenTree::Group(Group::new(*d, t))])
-                }
-                input.pop();
-                continue;
-            }
-        };
+    let span = match donor {
+        TokenTree::Group(g) => match g.stream().into_iter().next() {
+            Some(t) => t.span(),
+            None => return error_at(g.span(), "Expected at least one token"),
+        },
+        t => t.span(),
+    };
+    let body = match body {
+        TokenTree::Group(g) => g.stream(),
+        t => return error_at(t.span(), "Expected group"),
+    };
 
-        let id = match t {
-            TokenTree::Group(g) => {
-                input.push((g.stream().into_iter(), None, g.delimiter()));
-                res.push(TokenStream::new());
-                continue;
-            }
-            TokenTree::Ident(id) => id,
-            t => {
-                res.last_mut().expect("5").extend([t]);
-                continue;
-            }
-        };
+    respan(body, span)
+}
 
-        let name = id.to_string();
-        let m = match Macro::from_name(&name, id.span()) {
-            None => {
-                res.last_mut().expect("6").extend([TokenTree::Ident(id)]);
-                continue;
-            }
-            Some(Macro::Dollar) => {
-                res.last_mut()
-                    .expect("7")
-                    .extend(dollar(TokenStream::new()));
+fn respan(input: TokenStream, span: Span) -> TokenStream {
+    input
+        .into_iter()
+        .map(|t| match t {
+            TokenTree::Group(g) => spanned(
+                TokenTree::Group(Group::new(
+                    g.delimiter(),
+                    respan(g.stream(), span),
+                )),
+                span,
+            ),
+            t => spanned(t, span),
+        })
+        .collect()
+}
+
+pub fn assert_ident(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    if !matches!(t, TokenTree::Ident(_)) {
+        return error_at(t.span(), "Expected an identifier");
+    }
+
+    let mut res = TokenStream::new();
+    res.extend([t]);
+    res
+}
+
+pub fn assert_literal(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    if !matches!(t, TokenTree::Literal(_)) {
+        return error_at(t.span(), "Expected a literal");
+    }
+
+    let mut res = TokenStream::new();
+    res.extend([t]);
+    res
+}
+
+/// Gets the only token in the input. Invisible groups around the token (as
+/// created by macro metavariables) are removed.
+fn single_token(
+    input: TokenStream,
+    pos: Span,
+) -> Result<TokenTree, TokenStream> {
+    let mut i = input.into_iter();
+    let t = match i.next() {
+        Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::None => {
+            return single_token(g.stream(), g.span());
+        }
+        Some(t) => t,
+        None => return Err(error_at(pos, "Expected one argument, got 0")),
+    };
+    if let Some(n) = i.next() {
+        return Err(error_range(pos, n.span(), "Macro takes only 1 token"));
+    }
+    Ok(t)
+}
+
+pub fn escape_ident(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let s = match get_str_lit(t.clone()) {
+        Some(s) => s,
+        None => return error_at(t.span(), "Expected string literal"),
+    };
+
+    let mut res = String::new();
+    for c in s.chars() {
+        if res.is_empty() && !unicode_ident::is_xid_start(c) && c != '_' {
+            res.push('_');
+            if !unicode_ident::is_xid_continue(c) {
                 continue;
             }
-            Some(m) => m,
-        };
+        }
+        if unicode_ident::is_xid_continue(c) {
+            res.push(c);
+        } else {
+            res.push('_');
+        }
+    }
 
-        let g = match i.next() {
-            Some(TokenTree::Group(g)) => g,
-            Some(TokenTree::Ident(id)) => {
-                if !matches!(m, Macro::Ignore) {
-                    return error_at(id.span(), "Expected '('");
-                }
+    if res.is_empty() {
+        return error_at(
+            t.span(),
+            "Cannot create identifier from empty string",
+        );
+    }
 
-                let iname = id.to_string();
-                if let Some(m) = Macro::from_name(&iname, id.span()) {
-                    if matches!(m, Macro::Dollar) {
-                        continue;
-                    }
-                } else {
-                    return error_at(
-                        id.span(),
-                        "Expected '(' or builtin macro",
-                    );
-                }
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Ident(Ident::new(&res, Span::call_site()))]);
+    r
+}
 
-                let mut pos = id.span();
-                if let Some(g) = i.next() {
-                    if let TokenTree::Group(g) = g {
-                        let l = input.pop().unwrap();
-                        let mut s = g.stream();
-                        s.extend(l.0);
-                        input.push((s.into_iter(), l.1, l.2));
-                        continue;
-                    } else {
-                        pos = g.span();
-                    }
-                }
+pub fn first_ident(input: TokenStream) -> TokenStream {
+    let mut input = vec![input.into_iter()];
+    let mut res = TokenStream::new();
 
-                return error_at(pos, "Expected '('");
+    while let Some(i) = input.last_mut() {
+        match i.next() {
+            Some(TokenTree::Group(g)) => input.push(g.stream().into_iter()),
+            Some(TokenTree::Ident(i)) => {
+                res.extend([TokenTree::Ident(i)]);
+                break;
             }
-            Some(t) => return error_at(t.span(), "Expected '('"),
+            Some(_) => {}
             None => {
-                return error_at(id.span(), "Expected '(' after builtin macro")
+                input.pop();
             }
-        };
-
-        if matches!(m, Macro::Identity) {
-            res.last_mut().expect("7").extend(g.stream())
-        } else if matches!(m, Macro::ToCase(_)) {
-            let mut s = TokenStream::new();
-            s.extend([TokenTree::Ident(Ident::new(
-                name.trim_matches('_'),
-                Span::call_site(),
-            ))]);
-            s.extend(g.stream().into_iter());
-            input.push((s.into_iter(), Some(m), g.delimiter()));
-            res.push(TokenStream::new());
-        } else {
-            input.push((g.stream().into_iter(), Some(m), g.delimiter()));
-            res.push(TokenStream::new());
         }
     }
 
-    res.pop().expect("8")
+    res
 }
 
-#[derive(Clone, Copy)]
-enum Macro {
-    Ignore,
-    Identity,
-    Dollar,
-    String,
-    Head,
-    Tail,
-    Start,
-    Last,
-    Reverse,
-    Identifier,
-    Stringify,
-    ReplaceNewline(Span),
-    StrReplace(Span),
-    ToCase(Span),
-}
+pub fn collect_idents(input: TokenStream) -> TokenStream {
+    let mut input = vec![input.into_iter()];
+    let mut res = TokenStream::new();
 
-impl Macro {
-    fn from_name(s: &str, pos: Span) -> Option<Macro> {
-        match s {
-            "__ignore__" => Some(Self::Ignore),
-            "__identity__" | "__id__" => Some(Self::Identity),
-            "__dollar__" | "__s__" => Some(Self::Dollar),
-            "__string__" | "__str__" => Some(Self::String),
-            "__head__" => Some(Self::Head),
-            "__tail__" => Some(Self::Tail),
-            "__start__" => Some(Self::Start),
-            "__last__" => Some(Self::Last),
-            "__reverse__" => Some(Self::Reverse),
-            "__identifier__" | "__ident__" => Some(Self::Identifier),
-            "__stringify__" | "__strfy__" => Some(Self::Stringify),
-            "__replace_newline__" | "__repnl__" => {
-                Some(Self::ReplaceNewline(pos))
-            }
-            "__str_replace__" | "__repstr__" => Some(Self::StrReplace(pos)),
-            s if s.starts_with("__") && s.ends_with("__") => {
-                let lc = s.to_lowercase();
-                if lc == "__tocase__" || lc == "__to_case__" {
-                    Some(Self::ToCase(pos))
-                } else {
-                    None
+    while let Some(i) = input.last_mut() {
+        match i.next() {
+            Some(TokenTree::Group(g)) => input.push(g.stream().into_iter()),
+            Some(TokenTree::Ident(i)) => res.extend([TokenTree::Ident(i)]),
+            Some(_) => {}
+            None => {
+                input.pop();
+            }
+        }
+    }
+
+    res
+}
+
+pub fn comment(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    if get_str_lit(t.clone()).is_none() {
+        return error_at(t.span(), "Expected string literal");
+    }
+    TokenStream::new()
+}
+
+/// Expands to `::core::compile_error!` with the stringified input as the
+/// message.
+///
+/// # Examples
+/// ```
+/// use place_macro_core::debug;
+/// use proc_macro2::{Span, TokenStream};
+///
+/// let input: TokenStream = "fn foo() -> u8 { 5 }".parse().unwrap();
+/// let res = debug(input, Span::call_site());
+/// assert_eq!(
+///     res.to_string(),
+///     r#":: core :: compile_error ! ("fn foo () -> u8 { 5 }")"#
+/// );
+/// ```
+pub fn debug(input: TokenStream, pos: Span) -> TokenStream {
+    error_at(pos, input.to_string())
+}
+
+pub fn note(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let msg = match get_str_lit(t.clone()) {
+        Some(s) => s,
+        None => return error_at(t.span(), "Expected string literal"),
+    };
+
+    // Proc macros cannot emit warnings on stable, so this creates deprecated
+    // item and uses it, the message is shown in the deprecation warning.
+    let res: TokenStream = format!(
+        "const _: () = {{
+            #[deprecated = {}]
+            struct Note;
+            let _ = Note;
+        }};",
+        Literal::string(&msg)
+    )
+    .parse()
+    .unwrap();
+
+    respan(res, pos)
+}
+
+/// Expands to `::core::compile_error!` with the given string literal as
+/// message.
+///
+/// # Examples
+/// ```
+/// use place_macro_core::place;
+/// use proc_macro2::TokenStream;
+///
+/// let input: TokenStream =
+///     "__compile_error__(__string__(\"Invalid \" name \".\"))"
+///         .parse()
+///         .unwrap();
+/// let res = place(input);
+/// assert_eq!(
+///     res.to_string(),
+///     r#":: core :: compile_error ! ("Invalid name.")"#
+/// );
+/// ```
+pub fn compile_error(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    match get_str_lit(t.clone()) {
+        Some(msg) => error_at(t.span(), msg),
+        None => error_at(t.span(), "Expected string literal"),
+    }
+}
+
+/// Fails if the key was already used. `used` contains the already used keys.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+///
+/// use place_macro_core::once;
+/// use proc_macro2::{Span, TokenStream};
+///
+/// let mut used = HashSet::new();
+/// let key: TokenStream = "foo".parse().unwrap();
+/// assert!(once(key.clone(), Span::call_site(), &mut used).is_empty());
+///
+/// let res = once(key, Span::call_site(), &mut used);
+/// assert_eq!(
+///     res.to_string(),
+///     r#":: core :: compile_error ! ("Duplicate `__once__` key `foo`")"#
+/// );
+/// ```
+pub fn once(
+    input: TokenStream,
+    pos: Span,
+    used: &mut HashSet<String>,
+) -> TokenStream {
+    let span = match input.clone().into_iter().next() {
+        Some(t) => t.span(),
+        None => return error_at(pos, "Expected key"),
+    };
+
+    let key = input.to_string();
+    if used.contains(&key) {
+        return error_at(span, format!("Duplicate `__once__` key `{key}`"));
+    }
+    used.insert(key);
+
+    TokenStream::new()
+}
+
+/// Stores the tokens in the group under the given name. Expands to nothing.
+pub fn set(
+    input: TokenStream,
+    pos: Span,
+    vars: &mut HashMap<String, TokenStream>,
+) -> TokenStream {
+    let [name, value] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+
+    let name = match name {
+        TokenTree::Ident(n) => n.to_string(),
+        t => return error_at(t.span(), "Expected identifier"),
+    };
+    let value = match value {
+        TokenTree::Group(g) => g.stream(),
+        t => return error_at(t.span(), "Expected group"),
+    };
+
+    vars.insert(name, value);
+    TokenStream::new()
+}
+
+/// Expands to the tokens stored under the given name.
+pub fn reference(
+    input: TokenStream,
+    pos: Span,
+    vars: &HashMap<String, TokenStream>,
+) -> TokenStream {
+    let name = match single_token(input, pos) {
+        Ok(TokenTree::Ident(n)) => n,
+        Ok(t) => return error_at(t.span(), "Expected identifier"),
+        Err(e) => return e,
+    };
+
+    match vars.get(&name.to_string()) {
+        Some(v) => v.clone(),
+        None => error_at(
+            name.span(),
+            format!("`{name}` was not set with `__set__`"),
+        ),
+    }
+}
+
+pub fn apply(input: TokenStream, pos: Span) -> TokenStream {
+    let mut tokens: Vec<_> = input.into_iter().collect();
+    let args = match tokens.pop() {
+        Some(TokenTree::Group(g)) => g,
+        Some(t) => return error_at(t.span(), "Expected group with arguments"),
+        None => return error_at(pos, "Expected two arguments, got 0"),
+    };
+    match tokens.pop() {
+        Some(t) if is_comma(&t) => {}
+        Some(t) => return error_range(pos, t.span(), "Expected comma."),
+        None => return error_at(pos, "Expected two arguments, got 1"),
+    }
+    if tokens.is_empty() {
+        return error_at(pos, "Expected macro name");
+    }
+
+    let name = token_concat(tokens.into_iter().collect(), false);
+    let name = match checked_ident(&name, pos) {
+        Ok(i) => i,
+        Err(e) => return e,
+    };
+    [
+        TokenTree::Ident(name),
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(args),
+    ]
+    .into_iter()
+    .collect()
+}
+
+pub fn literal_kind(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let kind = match litrs::Literal::try_from(t.clone()) {
+        Ok(litrs::Literal::Bool(_)) => "bool",
+        Ok(litrs::Literal::Integer(_)) => "int",
+        Ok(litrs::Literal::Float(_)) => "float",
+        Ok(litrs::Literal::Char(_)) => "char",
+        Ok(litrs::Literal::String(_)) => "string",
+        Ok(litrs::Literal::Byte(_)) => "byte",
+        Ok(litrs::Literal::ByteString(_)) => "bytestring",
+        Err(_) => return error_at(t.span(), "Expected literal"),
+    };
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::string(kind))]);
+    res
+}
+
+pub fn quote_each(input: TokenStream) -> TokenStream {
+    let mut res = TokenStream::new();
+    for (n, t) in input.into_iter().enumerate() {
+        if n != 0 {
+            res.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        }
+        res.extend([TokenTree::Literal(Literal::string(&t.to_string()))]);
+    }
+    res
+}
+
+pub fn path_first(input: TokenStream) -> TokenStream {
+    let mut res = TokenStream::new();
+    res.extend(path_segments(input).into_iter().take(1));
+    res
+}
+
+pub fn path_last(input: TokenStream) -> TokenStream {
+    let mut res = TokenStream::new();
+    res.extend(path_segments(input).pop());
+    res
+}
+
+/// Gets the identifiers in path. Everything after `<` (generic arguments) is
+/// ignored.
+fn path_segments(input: TokenStream) -> Vec<TokenTree> {
+    input
+        .into_iter()
+        .take_while(
+            |t| !matches!(t, TokenTree::Punct(p) if p.as_char() == '<'),
+        )
+        .filter(|t| matches!(t, TokenTree::Ident(_)))
+        .collect()
+}
+
+pub fn join_path(input: TokenStream) -> TokenStream {
+    let mut res = TokenStream::new();
+    for (n, t) in input.into_iter().filter(|t| !is_comma(t)).enumerate() {
+        if n != 0 {
+            res.extend([
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            ]);
+        }
+        res.extend([t]);
+    }
+    res
+}
+
+/// Expands to the first group if the environment variable
+/// `CARGO_FEATURE_<NAME>` is set, otherwise expands to the second group.
+/// Cargo sets the variable only for build scripts, so it must be passed to
+/// the compiler by build script.
+///
+/// # Examples
+/// ```
+/// use place_macro_core::if_feature;
+/// use proc_macro2::{Span, TokenStream};
+///
+/// let input: TokenStream =
+///     r#""my-feature", (enabled), (disabled)"#.parse().unwrap();
+///
+/// std::env::remove_var("CARGO_FEATURE_MY_FEATURE");
+/// let res = if_feature(input.clone(), Span::call_site());
+/// assert_eq!(res.to_string(), "disabled");
+///
+/// std::env::set_var("CARGO_FEATURE_MY_FEATURE", "1");
+/// let res = if_feature(input, Span::call_site());
+/// assert_eq!(res.to_string(), "enabled");
+/// ```
+#[cfg(feature = "std")]
+pub fn if_feature(input: TokenStream, pos: Span) -> TokenStream {
+    let [name, then, els] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+
+    let name = match get_str_lit(name.clone()) {
+        Some(n) => n,
+        None => return error_at(name.span(), "Expected string literal"),
+    };
+    let (then, els) = match (then, els) {
+        (TokenTree::Group(t), TokenTree::Group(e)) => (t, e),
+        (TokenTree::Group(_), t) | (t, _) => {
+            return error_at(t.span(), "Expected group")
+        }
+    };
+
+    let var =
+        format!("CARGO_FEATURE_{}", name.to_uppercase().replace('-', "_"));
+    if std::env::var_os(var).is_some() {
+        then.stream()
+    } else {
+        els.stream()
+    }
+}
+
+pub fn stringify_pretty(input: TokenStream) -> TokenStream {
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::string(&pretty_string(input)))]);
+    res
+}
+
+/// Part of the input of `pretty_string`.
+enum PrettyPart {
+    /// Identifier, literal or lifetime.
+    Word(String),
+    /// Operator, it may consist of multiple punctuation characters.
+    Op(String),
+    Group(Delimiter, TokenStream),
+}
+
+/// Keywords after which there is space before parenthesis and after which
+/// operators are unary.
+const PRETTY_KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "dyn", "else", "enum", "extern",
+    "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+    "ref", "return", "static", "struct", "trait", "type", "unsafe", "use",
+    "where", "while", "yield",
+];
+
+/// Operators that consist of multiple characters, longer operators first.
+const PRETTY_OPS: &[&str] = &[
+    "..=", "...", "<<=", ">>=", "::", "->", "=>", "==", "!=", "<=", ">=",
+    "&&", "||", "+=", "-=", "*=", "/=", "%=", "^=", "&=", "|=", "<<", ">>",
+    "..",
+];
+
+/// Stringifies the tokens with spacing similar to rustfmt:
+/// - binary operators have space on both sides
+/// - unary operators, `.`, `::`, `?` and `!` of macro invocation have no
+///   spaces around them
+/// - `,`, `;` and `:` are followed by space
+/// - there is no space inside parenthesis and brackets and there is no space
+///   before them when they follow identifier (call or indexing)
+/// - braces have space inside them and before them
+/// - `<` and `>` of generics have no spaces around them
+/// - closure parameters have no space inside the `|`
+fn pretty_string(input: TokenStream) -> String {
+    let is_keyword = |p: Option<&PrettyPart>| match p {
+        Some(PrettyPart::Word(w)) => PRETTY_KEYWORDS.contains(&w.as_str()),
+        _ => false,
+    };
+
+    let parts = pretty_parts(input);
+    let mut res = String::new();
+    let mut space_after = false;
+    let mut generics = 0;
+    let mut generic_close = false;
+    let mut closure = false;
+
+    for (n, part) in parts.iter().enumerate() {
+        let prev = n.checked_sub(1).map(|n| &parts[n]);
+        let prev2 = n.checked_sub(2).map(|n| &parts[n]);
+        // whether operator here would be unary
+        let prefix = match prev {
+            None => true,
+            Some(PrettyPart::Op(o)) => !generic_close && o != "?",
+            Some(PrettyPart::Word(_)) => is_keyword(prev),
+            Some(PrettyPart::Group(..)) => false,
+        };
+        let after_name = matches!(prev, Some(PrettyPart::Word(_)))
+            && !is_keyword(prev)
+            || matches!(prev, Some(PrettyPart::Group(..)))
+            || generic_close;
+        let was_generic_close = generic_close;
+        generic_close = false;
+
+        let (before, after) = match part {
+            PrettyPart::Word(_) => (true, true),
+            PrettyPart::Group(Delimiter::Brace, _) => {
+                (!matches!(prev, Some(PrettyPart::Op(o)) if o == "::"), true)
+            }
+            PrettyPart::Group(Delimiter::None, _) => (true, true),
+            PrettyPart::Group(..) => {
+                (prev.is_some() && !after_name && !was_generic_close, true)
+            }
+            PrettyPart::Op(o) => match o.as_str() {
+                "," | ";" | ":" | "?" => (false, true),
+                "." => (false, false),
+                "::" => (!after_name, false),
+                "#" | "$" => (true, false),
+                "!" if after_name
+                    || matches!(prev, Some(PrettyPart::Op(o)) if o == "#") =>
+                {
+                    (false, false)
+                }
+                "<" if matches!(prev, Some(PrettyPart::Op(o)) if o == "::")
+                    || matches!(
+                        prev,
+                        Some(PrettyPart::Word(w)) if w == "impl"
+                            || w.starts_with(char::is_uppercase)
+                    )
+                    || matches!(
+                        prev2,
+                        Some(PrettyPart::Word(w)) if matches!(
+                            w.as_str(),
+                            "fn" | "struct" | "enum" | "trait" | "type"
+                                | "union"
+                        )
+                    ) =>
+                {
+                    generics += 1;
+                    (false, false)
+                }
+                ">" | ">>" if generics > 0 => {
+                    generics -= o.len().min(generics);
+                    generic_close = true;
+                    (false, true)
+                }
+                "|" if closure => {
+                    closure = false;
+                    (false, true)
+                }
+                "|" if prefix => {
+                    closure = true;
+                    (true, false)
+                }
+                ".." | "..=" | "..." if !prefix => (false, false),
+                "-" | "*" | "&" | "&&" | "!" | "~" | ".." | "..=" | "..."
+                    if prefix =>
+                {
+                    (true, false)
+                }
+                _ => (true, true),
+            },
+        };
+
+        if space_after && before {
+            res.push(' ');
+        }
+        space_after = after;
+
+        match part {
+            PrettyPart::Word(w) | PrettyPart::Op(w) => res += w,
+            PrettyPart::Group(d, s) => {
+                let inner = pretty_string(s.clone());
+                match d {
+                    Delimiter::Parenthesis => res += &format!("({inner})"),
+                    Delimiter::Bracket => res += &format!("[{inner}]"),
+                    Delimiter::Brace if inner.is_empty() => res += "{}",
+                    Delimiter::Brace => res += &format!("{{ {inner} }}"),
+                    Delimiter::None => res += &inner,
+                }
+            }
+        }
+    }
+
+    res
+}
+
+/// Splits the tokens into parts for `pretty_string`.
+fn pretty_parts(input: TokenStream) -> Vec<PrettyPart> {
+    let mut res = vec![];
+    let mut i = input.into_iter().peekable();
+    // characters of joint punctuation
+    let mut punct = String::new();
+
+    while let Some(t) = i.next() {
+        let p = match t {
+            TokenTree::Punct(p) => p,
+            TokenTree::Group(g) => {
+                res.push(PrettyPart::Group(g.delimiter(), g.stream()));
+                continue;
+            }
+            t => {
+                res.push(PrettyPart::Word(t.to_string()));
+                continue;
+            }
+        };
+
+        if p.as_char() == '\'' {
+            if let Some(TokenTree::Ident(l)) = i.peek() {
+                res.push(PrettyPart::Word(format!("'{l}")));
+                i.next();
+                continue;
+            }
+        }
+
+        punct.push(p.as_char());
+        let joint = match i.peek() {
+            Some(TokenTree::Punct(n)) => n.as_char() != '\'',
+            _ => false,
+        };
+        if p.spacing() == Spacing::Joint && joint {
+            continue;
+        }
+
+        // split the joint punctuation into operators
+        let mut rest = punct.as_str();
+        while !rest.is_empty() {
+            let len = PRETTY_OPS
+                .iter()
+                .find(|o| rest.starts_with(*o))
+                .map_or(1, |o| o.len());
+            res.push(PrettyPart::Op(rest[..len].to_string()));
+            rest = &rest[len..];
+        }
+        punct.clear();
+    }
+
+    res
+}
+
+/// Splits the string into lines. Returns the lines and whether the string
+/// ends with newline. Both `\n` and `\r\n` are recognized as newlines.
+fn split_lines(s: &str) -> (Vec<&str>, bool) {
+    (s.lines().collect(), s.ends_with('\n'))
+}
+
+/// Joins the lines with `\n`, optionally adding trailing newline.
+fn join_lines<'a>(
+    lines: impl IntoIterator<Item = &'a str>,
+    nl: bool,
+) -> String {
+    let mut res = lines.into_iter().collect::<Vec<_>>().join("\n");
+    if nl {
+        res.push('\n');
+    }
+    res
+}
+
+pub fn unique_lines(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let s = match get_str_lit(t.clone()) {
+        Some(s) => s,
+        None => return error_at(t.span(), "Expected string literal"),
+    };
+
+    let (lines, nl) = split_lines(&s);
+    let mut seen = HashSet::new();
+    let res = join_lines(lines.into_iter().filter(|l| seen.insert(*l)), nl);
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&res))]);
+    r
+}
+
+pub fn sort_lines(input: TokenStream, pos: Span) -> TokenStream {
+    let mut i = input.clone().into_iter();
+    let has_flag =
+        matches!(i.nth(1), Some(c) if is_comma(&c)) && i.next().is_some();
+    let (rev, t) = if has_flag {
+        let [f, t] = match get_args(input, pos) {
+            Ok(a) => a,
+            Err(e) => return e,
+        };
+        if get_str_lit(f.clone()).as_deref() != Some("rev") {
+            return error_at(f.span(), "Expected \"rev\"");
+        }
+        (true, t)
+    } else {
+        match get_args(input, pos) {
+            Ok([t]) => (false, t),
+            Err(e) => return e,
+        }
+    };
+    let s = match get_str_lit(t.clone()) {
+        Some(s) => s,
+        None => return error_at(t.span(), "Expected string literal"),
+    };
+
+    let (mut lines, nl) = split_lines(&s);
+    lines.sort_unstable();
+    if rev {
+        lines.reverse();
+    }
+    let res = join_lines(lines, nl);
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&res))]);
+    r
+}
+
+/// Checks that the input is valid sequence of items. If it is, it is returned
+/// unchanged, otherwise the result is error pointing to the problem.
+///
+/// In `place` this is `__expand_and_parse__` and the macros in its arguments
+/// are expanded before the check.
+///
+/// # Examples
+/// ```
+/// use place_macro_core::{expand_and_parse, place};
+/// use proc_macro2::{Span, TokenStream};
+///
+/// let input: TokenStream = "fn foo() -> u8 { 5 } struct A;".parse().unwrap();
+/// let res = expand_and_parse(input.clone(), Span::call_site());
+/// assert_eq!(res.to_string(), input.to_string());
+///
+/// let input: TokenStream = "fn foo() -> u8 { 5 } struct A".parse().unwrap();
+/// let res = expand_and_parse(input, Span::call_site());
+/// assert!(res.to_string().starts_with(":: core :: compile_error !"));
+///
+/// let input: TokenStream =
+///     "__expand_and_parse__(fn __ident__(a b)() {})".parse().unwrap();
+/// assert_eq!(place(input).to_string(), "fn ab () { }");
+///
+/// let input: TokenStream =
+///     "__expand_and_parse__(fn __string__(a b)() {})".parse().unwrap();
+/// let res = place(input).to_string();
+/// assert!(res.starts_with(":: core :: compile_error !"));
+/// ```
+#[cfg(feature = "syn")]
+pub fn expand_and_parse(input: TokenStream, pos: Span) -> TokenStream {
+    match syn::parse2::<syn::File>(input.clone()) {
+        Ok(_) => input,
+        Err(e) => error_range(pos, e.span(), e.to_string()),
+    }
+}
+
+/// Splits generic parameters into the individual parameters. The parameters
+/// may be enclosed in `<` and `>`, the returned bool tells whether they were.
+fn generic_params(
+    input: TokenStream,
+    pos: Span,
+) -> Result<(Vec<Vec<TokenTree>>, bool), TokenStream> {
+    let mut tokens: Vec<_> = input.into_iter().collect();
+    if let [TokenTree::Group(g)] = tokens.as_slice() {
+        if g.delimiter() == Delimiter::None {
+            return generic_params(g.stream(), g.span());
+        }
+    }
+
+    let angled = tokens.first().is_some_and(|t| is_punct(t, '<'));
+    if angled {
+        match tokens.last() {
+            Some(TokenTree::Punct(p))
+                if p.as_char() == '>' && tokens.len() > 1 => {}
+            Some(t) => return Err(error_range(pos, t.span(), "Expected '>'")),
+            None => unreachable!(),
+        }
+        tokens.pop();
+        tokens.remove(0);
+    }
+
+    let nested = angle_ranges(&tokens);
+    let mut res = vec![];
+    let mut param = vec![];
+    for (i, t) in tokens.into_iter().enumerate() {
+        if is_comma(&t) && !nested.iter().any(|r| r.contains(&i)) {
+            if param.is_empty() {
+                return Err(error_range(
+                    pos,
+                    t.span(),
+                    "Expected generic parameter",
+                ));
+            }
+            res.push(std::mem::take(&mut param));
+            continue;
+        }
+        param.push(t);
+    }
+    if !param.is_empty() {
+        res.push(param);
+    }
+
+    Ok((res, angled))
+}
+
+/// Finds the name of generic parameter (with `'` for lifetimes). Returns
+/// whether it is const parameter and the range of the name in the parameter.
+/// Attributes of the parameter are skipped.
+fn generic_param_name(param: &[TokenTree]) -> Option<(bool, Range<usize>)> {
+    let mut i = 0;
+    while let [TokenTree::Punct(p), TokenTree::Group(g), ..] = &param[i..] {
+        if p.as_char() != '#' || g.delimiter() != Delimiter::Bracket {
+            break;
+        }
+        i += 2;
+    }
+
+    match &param[i..] {
+        [TokenTree::Punct(p), TokenTree::Ident(_), ..]
+            if p.as_char() == '\'' =>
+        {
+            Some((false, i..i + 2))
+        }
+        [TokenTree::Ident(c), TokenTree::Ident(_), ..] if c == "const" => {
+            Some((true, i + 1..i + 2))
+        }
+        [TokenTree::Ident(_), ..] => Some((false, i..i + 1)),
+        _ => None,
+    }
+}
+
+pub fn type_params(input: TokenStream, pos: Span) -> TokenStream {
+    let params = match generic_params(input, pos) {
+        Ok((p, _)) => p,
+        Err(e) => return e,
+    };
+
+    let mut res = TokenStream::new();
+    for (n, param) in params.iter().enumerate() {
+        let Some((_, name)) = generic_param_name(param) else {
+            return error_at(param[0].span(), "Expected generic parameter");
+        };
+        if n != 0 {
+            res.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        }
+        res.extend(param[name].iter().cloned());
+    }
+    res
+}
+
+pub fn strip_bounds(input: TokenStream, pos: Span) -> TokenStream {
+    let (params, angled) = match generic_params(input, pos) {
+        Ok(p) => p,
+        Err(e) => return e,
+    };
+
+    let mut res = TokenStream::new();
+    if angled {
+        res.extend([TokenTree::Punct(Punct::new('<', Spacing::Alone))]);
+    }
+    for (n, param) in params.iter().enumerate() {
+        let Some((cnst, name)) = generic_param_name(param) else {
+            return error_at(param[0].span(), "Expected generic parameter");
+        };
+        if n != 0 {
+            res.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        }
+        res.extend(param[..name.end].iter().cloned());
+        if cnst {
+            // keep the type of const parameter, remove the default
+            res.extend(
+                param[name.end..]
+                    .iter()
+                    .take_while(|t| !is_punct(t, '='))
+                    .cloned(),
+            );
+        }
+    }
+    if angled {
+        res.extend([TokenTree::Punct(Punct::new('>', Spacing::Alone))]);
+    }
+    res
+}
+
+/// Converts single identifier or string literal to the given case.
+fn case_preset(case: Case, input: TokenStream, pos: Span) -> TokenStream {
+    match single_token(input, pos) {
+        Ok(t) => convert_case(case, t),
+        Err(e) => e,
+    }
+}
+
+pub fn screaming(input: TokenStream, pos: Span) -> TokenStream {
+    case_preset(Case::UpperSnake, input, pos)
+}
+
+pub fn pascal(input: TokenStream, pos: Span) -> TokenStream {
+    case_preset(Case::Pascal, input, pos)
+}
+
+pub fn camel(input: TokenStream, pos: Span) -> TokenStream {
+    case_preset(Case::Camel, input, pos)
+}
+
+pub fn snake(input: TokenStream, pos: Span) -> TokenStream {
+    case_preset(Case::Snake, input, pos)
+}
+
+pub fn kebab(input: TokenStream, pos: Span) -> TokenStream {
+    case_preset(Case::Kebab, input, pos)
+}
+
+/// Gets the value of integer literal.
+fn get_uint_lit(tt: TokenTree) -> Option<u128> {
+    match tt {
+        TokenTree::Group(g) => {
+            let mut i = g.stream().into_iter();
+            match (i.next(), i.next()) {
+                (Some(t), None) => get_uint_lit(t),
+                _ => None,
+            }
+        }
+        TokenTree::Literal(l) => {
+            litrs::IntegerLit::try_from(l).ok()?.value::<u128>()
+        }
+        _ => None,
+    }
+}
+
+pub fn ordinal(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let Some(n) = get_uint_lit(t.clone()) else {
+        return error_at(t.span(), "Expected non-negative integer literal");
+    };
+
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::string(&format!("{n}{suffix}")))]);
+    res
+}
+
+/// Gets the text of identifier or string literal. The bool is true for
+/// identifier.
+fn get_word(tt: TokenTree) -> Result<(String, bool), TokenStream> {
+    match tt {
+        TokenTree::Ident(i) => Ok((i.to_string(), true)),
+        TokenTree::Group(g) if g.delimiter() == Delimiter::None => {
+            let mut i = g.stream().into_iter();
+            match (i.next(), i.next()) {
+                (Some(t), None) => get_word(t),
+                _ => Err(error_at(g.span(), "Expected identifier or string")),
+            }
+        }
+        t => match get_str_lit(t.clone()) {
+            Some(s) => Ok((s.into_owned(), false)),
+            None => Err(error_at(t.span(), "Expected identifier or string")),
+        },
+    }
+}
+
+/// Converts word created by `plural` or `singular` to token. It is
+/// identifier if `ident` is true, otherwise it is string literal.
+fn word_token(word: &str, ident: bool, span: Span) -> TokenStream {
+    let t = if !ident {
+        TokenTree::Literal(Literal::string(word))
+    } else if is_ident(word) {
+        TokenTree::Ident(Ident::new(word, Span::call_site()))
+    } else {
+        return error_at(span, format!("'{word}' is not valid identifier"));
+    };
+
+    let mut res = TokenStream::new();
+    res.extend([t]);
+    res
+}
+
+/// Changes word with `f` or replaces it with the optional second argument.
+/// Used by `plural` and `singular`.
+fn change_word(
+    input: TokenStream,
+    pos: Span,
+    f: impl FnOnce(&str) -> String,
+) -> TokenStream {
+    let mut i = input.clone().into_iter();
+    let has_override =
+        matches!(i.nth(1), Some(c) if is_comma(&c)) && i.next().is_some();
+    let (word, over) = if has_override {
+        match get_args(input, pos) {
+            Ok([w, o]) => (w, Some(o)),
+            Err(e) => return e,
+        }
+    } else {
+        match single_token(input, pos) {
+            Ok(w) => (w, None),
+            Err(e) => return e,
+        }
+    };
+
+    let span = word.span();
+    let (word, ident) = match get_word(word) {
+        Ok(w) => w,
+        Err(e) => return e,
+    };
+    let res = match over.map(get_word) {
+        Some(Ok((o, _))) => o,
+        Some(Err(e)) => return e,
+        None => f(&word),
+    };
+
+    word_token(&res, ident, span)
+}
+
+/// Appends the suffix to the word. The suffix is uppercase if the word is
+/// uppercase.
+fn add_suffix(word: &str, suffix: &str) -> String {
+    if word.chars().any(|c| c.is_alphabetic())
+        && !word.chars().any(|c| c.is_lowercase())
+    {
+        word.to_string() + &suffix.to_uppercase()
+    } else {
+        word.to_string() + suffix
+    }
+}
+
+pub fn plural(input: TokenStream, pos: Span) -> TokenStream {
+    change_word(input, pos, |w| {
+        let lw = w.to_lowercase();
+        let consonant_y = lw.strip_suffix('y').is_some_and(|r| {
+            !r.is_empty() && !r.ends_with(['a', 'e', 'i', 'o', 'u'])
+        });
+        if consonant_y {
+            add_suffix(&w[..w.len() - 1], "ies")
+        } else if lw.ends_with(['s', 'x', 'z'])
+            || lw.ends_with("ch")
+            || lw.ends_with("sh")
+        {
+            add_suffix(w, "es")
+        } else {
+            add_suffix(w, "s")
+        }
+    })
+}
+
+pub fn singular(input: TokenStream, pos: Span) -> TokenStream {
+    change_word(input, pos, |w| {
+        let lw = w.to_lowercase();
+        if lw.len() > 3 && lw.ends_with("ies") {
+            add_suffix(&w[..w.len() - 3], "y")
+        } else if ["sses", "xes", "zes", "ches", "shes"]
+            .iter()
+            .any(|s| lw.ends_with(s))
+        {
+            w[..w.len() - 2].to_string()
+        } else if lw.ends_with('s')
+            && !["ss", "us", "is"].iter().any(|s| lw.ends_with(s))
+        {
+            w[..w.len() - 1].to_string()
+        } else {
+            w.to_string()
+        }
+    })
+}
+
+pub fn byte(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+
+    let c = match litrs::Literal::try_from(t.clone()) {
+        Ok(litrs::Literal::Integer(i)) => match i.value::<u8>() {
+            Some(b) => b,
+            None => {
+                return error_at(t.span(), "Byte must be in range 0..=255")
+            }
+        },
+        Ok(litrs::Literal::Char(c)) => match u8::try_from(c.value()) {
+            Ok(b) if b.is_ascii() => b,
+            _ => return error_at(t.span(), "Expected ASCII character"),
+        },
+        Ok(litrs::Literal::String(s)) => match s.value().as_bytes() {
+            [b] if b.is_ascii() => *b,
+            _ => return error_at(t.span(), "Expected single ASCII character"),
+        },
+        _ => {
+            return error_at(
+                t.span(),
+                "Expected integer, char or string literal",
+            )
+        }
+    };
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::byte_character(c))]);
+    res
+}
+
+/// Transliteration of common latin characters to ASCII for `to_ascii`. The
+/// first string contains the characters and the second their replacement.
+const ASCII_FOLD: &[(&str, &str)] = &[
+    ("àáâãäåāăą", "a"),
+    ("ÀÁÂÃÄÅĀĂĄ", "A"),
+    ("æ", "ae"),
+    ("Æ", "AE"),
+    ("çćĉċč", "c"),
+    ("ÇĆĈĊČ", "C"),
+    ("ďđð", "d"),
+    ("ĎĐÐ", "D"),
+    ("èéêëēĕėęě", "e"),
+    ("ÈÉÊËĒĔĖĘĚ", "E"),
+    ("ĝğġģ", "g"),
+    ("ĜĞĠĢ", "G"),
+    ("ĥħ", "h"),
+    ("ĤĦ", "H"),
+    ("ìíîïĩīĭįı", "i"),
+    ("ÌÍÎÏĨĪĬĮİ", "I"),
+    ("ĵ", "j"),
+    ("Ĵ", "J"),
+    ("ķ", "k"),
+    ("Ķ", "K"),
+    ("ĺļľŀł", "l"),
+    ("ĹĻĽĿŁ", "L"),
+    ("ñńņň", "n"),
+    ("ÑŃŅŇ", "N"),
+    ("òóôõöøōŏő", "o"),
+    ("ÒÓÔÕÖØŌŎŐ", "O"),
+    ("œ", "oe"),
+    ("Œ", "OE"),
+    ("ŕŗř", "r"),
+    ("ŔŖŘ", "R"),
+    ("śŝşš", "s"),
+    ("ŚŜŞŠ", "S"),
+    ("ß", "ss"),
+    ("ţťŧ", "t"),
+    ("ŢŤŦ", "T"),
+    ("þ", "th"),
+    ("Þ", "TH"),
+    ("ùúûüũūŭůűų", "u"),
+    ("ÙÚÛÜŨŪŬŮŰŲ", "U"),
+    ("ŵ", "w"),
+    ("Ŵ", "W"),
+    ("ýÿŷ", "y"),
+    ("ÝŸŶ", "Y"),
+    ("źżž", "z"),
+    ("ŹŻŽ", "Z"),
+];
+
+pub fn to_ascii(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let s = match get_str_lit(t.clone()) {
+        Some(s) => s,
+        None => return error_at(t.span(), "Expected string literal"),
+    };
+
+    let mut res = String::new();
+    for c in s.chars() {
+        if c.is_ascii() {
+            res.push(c);
+        } else if let Some((_, r)) =
+            ASCII_FOLD.iter().find(|(f, _)| f.contains(c))
+        {
+            res += r;
+        }
+    }
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&res))]);
+    r
+}
+
+pub fn literal_value(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+
+    let v = match litrs::Literal::try_from(t.clone()) {
+        Ok(litrs::Literal::Integer(i)) => match i.value::<u128>() {
+            Some(v) => v,
+            None => return error_at(t.span(), "Integer is too large"),
+        },
+        Ok(litrs::Literal::Char(c)) => c.value() as u128,
+        Ok(litrs::Literal::Byte(b)) => b.value() as u128,
+        _ => {
+            return error_at(
+                t.span(),
+                "Expected integer, char or byte literal",
+            )
+        }
+    };
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::u128_unsuffixed(v))]);
+    res
+}
+
+/// Gets the value of integer literal that may be preceded by `-`.
+fn get_int(arg: &[TokenTree]) -> Option<i128> {
+    match arg {
+        [TokenTree::Group(g)] if g.delimiter() == Delimiter::None => {
+            get_int(&g.stream().into_iter().collect::<Vec<_>>())
+        }
+        [TokenTree::Punct(p), l] if p.as_char() == '-' => {
+            get_uint_lit(l.clone())
+                .and_then(|v| 0_i128.checked_sub_unsigned(v))
+        }
+        [l] => get_uint_lit(l.clone()).and_then(|v| v.try_into().ok()),
+        _ => None,
+    }
+}
+
+/// Gets exactly `N` comma separated integer arguments. The integers may be
+/// negative.
+fn get_int_args<const N: usize>(
+    input: TokenStream,
+    pos: Span,
+) -> Result<[(i128, Span); N], TokenStream> {
+    let args = split_args(input);
+    if args.len() != N {
+        return Err(error_at(
+            pos,
+            format!("Expected {N} arguments, got {}", args.len()),
+        ));
+    }
+
+    let mut res = [(0, pos); N];
+    for (r, a) in res.iter_mut().zip(args) {
+        let Some(f) = a.first() else {
+            return Err(error_at(pos, "Expected integer literal"));
+        };
+        match get_int(&a) {
+            Some(v) => *r = (v, f.span()),
+            None => {
+                return Err(error_at(f.span(), "Expected integer literal"))
+            }
+        }
+    }
+    Ok(res)
+}
+
+pub fn clamp(input: TokenStream, pos: Span) -> TokenStream {
+    let [(v, _), (lo, los), (hi, his)] = match get_int_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    if lo > hi {
+        return error_range(
+            los,
+            his,
+            "Lower bound is larger than the upper bound",
+        );
+    }
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::i128_unsuffixed(
+        v.clamp(lo, hi),
+    ))]);
+    res
+}
+
+pub fn escape_braces(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let s = match get_str_lit(t.clone()) {
+        Some(s) => s,
+        None => return error_at(t.span(), "Expected string literal"),
+    };
+
+    let res = s.replace('{', "{{").replace('}', "}}");
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&res))]);
+    r
+}
+
+pub fn select_index(input: TokenStream, pos: Span) -> TokenStream {
+    let mut args = split_args(input).into_iter();
+    let idx = match args.next() {
+        Some(i) if !i.is_empty() => i,
+        _ => return error_at(pos, "Expected index"),
+    };
+    let Some(n) = get_int(&idx).and_then(|n| usize::try_from(n).ok()) else {
+        return error_at(
+            idx[0].span(),
+            "Expected non-negative integer literal",
+        );
+    };
+
+    let mut groups = vec![];
+    for a in args {
+        match a.as_slice() {
+            [TokenTree::Group(g)] => groups.push(g.stream()),
+            [t, ..] => return error_at(t.span(), "Expected group"),
+            [] => return error_at(pos, "Expected group"),
+        }
+    }
+
+    match groups.into_iter().nth(n) {
+        Some(g) => g,
+        None => error_at(idx[0].span(), format!("Index {n} is out of range")),
+    }
+}
+
+/// Gets the count that is the first argument and its span. The comma after
+/// the count is also consumed.
+fn get_count(
+    i: &mut impl Iterator<Item = TokenTree>,
+    pos: Span,
+) -> Result<(usize, Span), TokenStream> {
+    let count: Vec<_> = i.take_while(|t| !is_comma(t)).collect();
+    let Some(f) = count.first() else {
+        return Err(error_at(pos, "Expected count"));
+    };
+    match get_int(&count).and_then(|n| usize::try_from(n).ok()) {
+        Some(n) => Ok((n, f.span())),
+        None => {
+            Err(error_at(f.span(), "Expected non-negative integer literal"))
+        }
+    }
+}
+
+/// Gets the count (with its span) and group that are the first two
+/// arguments and the rest of the input (that may contain commas). Used by
+/// macros with arguments `N, (group), tokens...`.
+fn count_group_rest(
+    input: TokenStream,
+    pos: Span,
+) -> Result<(usize, Span, TokenStream, TokenStream), TokenStream> {
+    let mut i = input.into_iter();
+    let (n, span) = get_count(&mut i, pos)?;
+
+    let group = match i.next() {
+        Some(TokenTree::Group(g)) => g.stream(),
+        Some(t) => return Err(error_at(t.span(), "Expected group")),
+        None => return Err(error_at(pos, "Expected group")),
+    };
+    match i.next() {
+        Some(t) if is_comma(&t) => {}
+        Some(t) => return Err(error_range(pos, t.span(), "Expected comma.")),
+        None => {}
+    }
+
+    Ok((n, span, group, i.collect()))
+}
+
+pub fn repeat_sep(input: TokenStream, pos: Span) -> TokenStream {
+    let (n, _, sep, tokens) = match count_group_rest(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+
+    let mut res = TokenStream::new();
+    for i in 0..n {
+        if i != 0 {
+            res.extend(sep.clone());
+        }
+        res.extend(tokens.clone());
+    }
+    res
+}
+
+pub fn map_str(input: TokenStream, pos: Span) -> TokenStream {
+    let mut args = split_args(input).into_iter();
+    let op = match args.next() {
+        Some(o) if !o.is_empty() => o,
+        _ => return error_at(pos, "Expected operation"),
+    };
+    let f: fn(&str) -> String = match op.as_slice() {
+        [o] => match get_str_lit(o.clone()).as_deref() {
+            Some("upper") => str::to_uppercase,
+            Some("lower") => str::to_lowercase,
+            Some("trim") => |s| s.trim().to_string(),
+            Some("capitalize") => |s| {
+                let mut c = s.chars();
+                c.next()
+                    .map(|f| f.to_uppercase().chain(c).collect())
+                    .unwrap_or_default()
+            },
+            _ => {
+                return error_at(
+                    o.span(),
+                    "Expected one of \"upper\", \"lower\", \"trim\" or \
+                    \"capitalize\"",
+                )
+            }
+        },
+        [_, t, ..] => return error_range(pos, t.span(), "Expected comma."),
+        [] => unreachable!(),
+    };
+
+    let mut res = TokenStream::new();
+    for (n, a) in args.enumerate() {
+        let s = match a.as_slice() {
+            [t] => get_str_lit(t.clone()),
+            _ => None,
+        };
+        let Some(s) = s else {
+            let span = a.first().map_or(pos, |t| t.span());
+            return error_at(span, "Expected string literal");
+        };
+        if n != 0 {
+            res.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        }
+        res.extend([TokenTree::Literal(Literal::string(&f(&s)))]);
+    }
+    res
+}
+
+/// Evaluator of integer expressions for `const_eval`.
+struct ConstEval<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl ConstEval<'_> {
+    /// Evaluates the whole expression.
+    fn eval(s: &str) -> Result<i128, String> {
+        let mut e = ConstEval { s, pos: 0 };
+        let res = e.sum()?;
+        match e.peek() {
+            None => Ok(res),
+            Some(c) => Err(e.unexpected(c)),
+        }
+    }
+
+    /// Expression with `+` and `-`.
+    fn sum(&mut self) -> Result<i128, String> {
+        let mut res = self.product()?;
+        loop {
+            let op: fn(i128, i128) -> Option<i128> = match self.peek() {
+                Some('+') => i128::checked_add,
+                Some('-') => i128::checked_sub,
+                _ => return Ok(res),
+            };
+            self.pos += 1;
+            res = op(res, self.product()?).ok_or("Overflow")?;
+        }
+    }
+
+    /// Expression with `*`, `/` and `%`.
+    fn product(&mut self) -> Result<i128, String> {
+        let mut res = self.unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(c @ ('*' | '/' | '%')) => c,
+                _ => return Ok(res),
+            };
+            self.pos += 1;
+            let r = self.unary()?;
+            res = match op {
+                '*' => res.checked_mul(r),
+                _ if r == 0 => return Err("Division by zero".into()),
+                '/' => res.checked_div(r),
+                _ => res.checked_rem(r),
+            }
+            .ok_or("Overflow")?;
+        }
+    }
+
+    /// Expression with unary `-` and `+`.
+    fn unary(&mut self) -> Result<i128, String> {
+        match self.peek() {
+            Some('-') => {
+                self.pos += 1;
+                self.unary()?.checked_neg().ok_or_else(|| "Overflow".into())
+            }
+            Some('+') => {
+                self.pos += 1;
+                self.unary()
+            }
+            _ => self.atom(),
+        }
+    }
+
+    /// Number or expression in parenthesis.
+    fn atom(&mut self) -> Result<i128, String> {
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let res = self.sum()?;
+                match self.peek() {
+                    Some(')') => {
+                        self.pos += 1;
+                        Ok(res)
+                    }
+                    Some(c) => Err(self.unexpected(c)),
+                    None => Err("Expected ')'".into()),
+                }
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let rest = &self.s[self.pos..];
+                let len = rest
+                    .find(|c: char| !c.is_ascii_digit() && c != '_')
+                    .unwrap_or(rest.len());
+                self.pos += len;
+                let mut res: i128 = 0;
+                for d in rest[..len].bytes().filter(|c| *c != b'_') {
+                    res = res
+                        .checked_mul(10)
+                        .and_then(|r| r.checked_add((d - b'0') as i128))
+                        .ok_or("Overflow")?;
+                }
+                Ok(res)
+            }
+            Some(c) => Err(self.unexpected(c)),
+            None => Err("Unexpected end of expression".into()),
+        }
+    }
+
+    /// Skips whitespace and gets the next character.
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.s[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+        self.s[self.pos..].chars().next()
+    }
+
+    fn unexpected(&self, c: char) -> String {
+        format!("Unexpected character '{c}' at position {}", self.pos)
+    }
+}
+
+pub fn const_eval(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let s = match get_str_lit(t.clone()) {
+        Some(s) => s,
+        None => return error_at(t.span(), "Expected string literal"),
+    };
+
+    match ConstEval::eval(&s) {
+        Ok(v) => {
+            let mut res = TokenStream::new();
+            res.extend([TokenTree::Literal(Literal::i128_unsuffixed(v))]);
+            res
+        }
+        Err(e) => error_at(t.span(), e),
+    }
+}
+
+pub fn pad_tokens(input: TokenStream, pos: Span) -> TokenStream {
+    let (n, _, filler, tokens) = match count_group_rest(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let filler: Vec<_> = filler.into_iter().collect();
+    let tokens: Vec<_> = tokens.into_iter().collect();
+    if filler.is_empty() && tokens.len() < n {
+        return error_at(pos, "Filler is empty");
+    }
+
+    let mut res = TokenStream::new();
+    res.extend(tokens.into_iter().chain(filler.into_iter().cycle()).take(n));
+    res
+}
+
+/// Checks whether there are no tokens, invisible groups without tokens are
+/// ignored.
+fn is_empty_stream(s: &TokenStream) -> bool {
+    s.clone().into_iter().all(|t| match t {
+        TokenTree::Group(g) => {
+            g.delimiter() == Delimiter::None && is_empty_stream(&g.stream())
+        }
+        _ => false,
+    })
+}
+
+pub fn with_default(input: TokenStream, pos: Span) -> TokenStream {
+    let [maybe, default] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let (TokenTree::Group(maybe), TokenTree::Group(default)) =
+        (&maybe, &default)
+    else {
+        let t = if matches!(maybe, TokenTree::Group(_)) {
+            default
+        } else {
+            maybe
+        };
+        return error_at(t.span(), "Expected group");
+    };
+
+    if is_empty_stream(&maybe.stream()) {
+        default.stream()
+    } else {
+        maybe.stream()
+    }
+}
+
+pub fn trim_idents(input: TokenStream, pos: Span) -> TokenStream {
+    let mut i = input.into_iter();
+    let marker = match i.next() {
+        Some(TokenTree::Ident(m)) => m.to_string(),
+        Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::None => {
+            match single_token(g.stream(), g.span()) {
+                Ok(TokenTree::Ident(m)) => m.to_string(),
+                Ok(t) => return error_at(t.span(), "Expected identifier"),
+                Err(e) => return e,
+            }
+        }
+        Some(t) => return error_at(t.span(), "Expected identifier"),
+        None => return error_at(pos, "Expected marker identifier"),
+    };
+    check_comma!(i, pos);
+
+    let is_marker = |t: Option<&TokenTree>| match t {
+        Some(TokenTree::Ident(i)) => *i == marker,
+        _ => false,
+    };
+    let mut tokens: Vec<_> = i.collect();
+    if is_marker(tokens.last()) {
+        tokens.pop();
+    }
+    if is_marker(tokens.first()) {
+        tokens.remove(0);
+    }
+
+    tokens.into_iter().collect()
+}
+
+pub fn is_keyword(input: TokenStream, pos: Span) -> TokenStream {
+    let word = match single_token(input, pos).and_then(get_word) {
+        Ok((w, _)) => w,
+        Err(e) => return e,
+    };
+
+    let res = KEYWORDS.contains(&word.as_str())
+        || EDITION_KEYWORDS.contains(&word.as_str());
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Ident(Ident::new(
+        if res { "true" } else { "false" },
+        Span::call_site(),
+    ))]);
+    r
+}
+
+/// Gets the value and power of two alignment for `align_up` and
+/// `align_down`.
+fn align_args(
+    input: TokenStream,
+    pos: Span,
+) -> Result<(i128, i128), TokenStream> {
+    let [(v, _), (a, aspan)] = get_int_args(input, pos)?;
+    if a <= 0 || a & (a - 1) != 0 {
+        return Err(error_at(aspan, "Alignment must be power of two"));
+    }
+    Ok((v, a))
+}
+
+pub fn align_up(input: TokenStream, pos: Span) -> TokenStream {
+    let (v, a) = match align_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let Some(r) = v.checked_add(a - 1).map(|v| v & !(a - 1)) else {
+        return error_at(pos, "Overflow");
+    };
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::i128_unsuffixed(r))]);
+    res
+}
+
+pub fn align_down(input: TokenStream, pos: Span) -> TokenStream {
+    let (v, a) = match align_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::i128_unsuffixed(v & !(a - 1)))]);
+    res
+}
+
+pub fn bit_width(input: TokenStream, pos: Span) -> TokenStream {
+    let [(v, span)] = match get_int_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    if v < 0 {
+        return error_at(span, "Value must not be negative");
+    }
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::u32_unsuffixed(
+        i128::BITS - v.leading_zeros(),
+    ))]);
+    res
+}
+
+pub fn enumerate(input: TokenStream) -> TokenStream {
+    let mut res = TokenStream::new();
+    for (n, t) in input.into_iter().enumerate() {
+        let mut pair = TokenStream::new();
+        pair.extend([TokenTree::Literal(Literal::usize_unsuffixed(n)), t]);
+        res.extend([TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            pair,
+        ))]);
+    }
+    res
+}
+
+pub fn flatten_deep(input: TokenStream) -> TokenStream {
+    let mut input = vec![input.into_iter()];
+    let mut res = TokenStream::new();
+
+    while let Some(i) = input.last_mut() {
+        match i.next() {
+            Some(TokenTree::Group(g)) => input.push(g.stream().into_iter()),
+            Some(t) => res.extend([t]),
+            None => {
+                input.pop();
+            }
+        }
+    }
+
+    res
+}
+
+pub fn unsuffix(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+
+    // Work with the text so that the value is not changed by conversions.
+    let (raw, suffix) = match litrs::Literal::try_from(t.clone()) {
+        Ok(litrs::Literal::Integer(i)) => {
+            (i.raw_input().to_owned(), i.suffix().to_owned())
+        }
+        Ok(litrs::Literal::Float(f)) => {
+            (f.raw_input().to_owned(), f.suffix().to_owned())
+        }
+        _ => return error_at(t.span(), "Expected numeric literal"),
+    };
+    let mut num = raw[..raw.len() - suffix.len()].to_owned();
+    // `2f32` would become integer
+    if suffix.starts_with('f') && !num.contains(['.', 'e', 'E']) {
+        num += ".0";
+    }
+
+    let Ok(mut lit) = num.parse::<Literal>() else {
+        return error_at(t.span(), "Failed to create literal");
+    };
+    lit.set_span(t.span());
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(lit)]);
+    res
+}
+
+/// Expands to string literal with the value of the given string literal
+/// escaped as it would be in rust source (without the quotes).
+///
+/// # Examples
+/// ```
+/// use place_macro_core::quote_str;
+/// use proc_macro2::{Span, TokenStream};
+///
+/// let input: TokenStream = r#""a\"b\\c\n""#.parse().unwrap();
+/// let res = quote_str(input, Span::call_site());
+///
+/// // Putting the value back into quotes gives the original literal.
+/// let lit = litrs::StringLit::try_from(res.into_iter().next().unwrap())
+///     .unwrap();
+/// let back: TokenStream = format!("\"{}\"", lit.value()).parse().unwrap();
+/// let back = litrs::StringLit::try_from(back.into_iter().next().unwrap())
+///     .unwrap();
+/// assert_eq!(back.value(), "a\"b\\c\n");
+/// ```
+pub fn quote_str(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let s = match get_str_lit(t.clone()) {
+        Some(s) => s,
+        None => return error_at(t.span(), "Expected string literal"),
+    };
+
+    let quoted = Literal::string(&s).to_string();
+    let res = &quoted[1..quoted.len() - 1];
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(res))]);
+    r
+}
+
+/// Gets the comma separated string literals. There may be trailing comma.
+fn get_str_list(
+    input: TokenStream,
+    pos: Span,
+) -> Result<Vec<String>, TokenStream> {
+    let mut res = vec![];
+    for a in split_args(input) {
+        let s = match a.as_slice() {
+            [t] => get_str_lit(t.clone()),
+            _ => None,
+        };
+        let Some(s) = s else {
+            let span = a.first().map_or(pos, |t| t.span());
+            return Err(error_at(span, "Expected string literal"));
+        };
+        res.push(s.into_owned());
+    }
+    Ok(res)
+}
+
+pub fn max_len(input: TokenStream, pos: Span) -> TokenStream {
+    let strs = match get_str_list(input, pos) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    let max = strs.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::usize_unsuffixed(max))]);
+    res
+}
+
+pub fn to_doc(input: TokenStream) -> TokenStream {
+    let s = format!("`{}`", pretty_string(input));
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::string(&s))]);
+    res
+}
+
+pub fn repeat_group(input: TokenStream, pos: Span) -> TokenStream {
+    let [n, group] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let Some(count) =
+        get_uint_lit(n.clone()).and_then(|n| usize::try_from(n).ok())
+    else {
+        return error_at(n.span(), "Expected non-negative integer literal");
+    };
+    if let Err(e) = check_repeat(count as u128, n.span()) {
+        return e;
+    }
+    if !matches!(group, TokenTree::Group(_)) {
+        return error_at(group.span(), "Expected group");
+    }
+
+    let mut res = TokenStream::new();
+    res.extend(std::iter::repeat(group).take(count));
+    res
+}
+
+pub fn reverse_path(input: TokenStream) -> TokenStream {
+    let leading = input
+        .clone()
+        .into_iter()
+        .next()
+        .is_some_and(|t| is_punct(&t, ':'));
+
+    let mut res = TokenStream::new();
+    for (n, t) in path_segments(input).into_iter().rev().enumerate() {
+        if n != 0 || leading {
+            res.extend([
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            ]);
+        }
+        res.extend([t]);
+    }
+    res
+}
+
+pub fn group_args(input: TokenStream) -> TokenStream {
+    let mut res = TokenStream::new();
+    for a in split_args(input) {
+        res.extend([TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            a.into_iter().collect(),
+        ))]);
+    }
+    res
+}
+
+/// Replaces the identifiers `__0__`, `__1__`, ... (even inside groups) with
+/// the token at the given index in `values`.
+fn fill_markers(template: TokenStream, values: &[TokenTree]) -> TokenStream {
+    template
+        .into_iter()
+        .map(|t| match t {
+            TokenTree::Group(g) => {
+                let mut res = Group::new(
+                    g.delimiter(),
+                    fill_markers(g.stream(), values),
+                );
+                res.set_span(g.span());
+                TokenTree::Group(res)
+            }
+            TokenTree::Ident(i) => {
+                let s = i.to_string();
+                let idx = s
+                    .strip_prefix("__")
+                    .and_then(|s| s.strip_suffix("__"))
+                    .and_then(|s| s.parse::<usize>().ok());
+                match idx.and_then(|i| values.get(i)) {
+                    Some(v) => v.clone(),
+                    None => TokenTree::Ident(i),
+                }
+            }
+            t => t,
+        })
+        .collect()
+}
+
+pub fn zip_with(input: TokenStream, pos: Span) -> TokenStream {
+    let args: [TokenTree; 3] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let [TokenTree::Group(tmp), TokenTree::Group(a), TokenTree::Group(b)] =
+        &args
+    else {
+        let t = args.iter().find(|t| !matches!(t, TokenTree::Group(_)));
+        return error_at(t.unwrap().span(), "Expected group");
+    };
+    let a: Vec<_> = a.stream().into_iter().collect();
+    let b: Vec<_> = b.stream().into_iter().collect();
+    if a.len() != b.len() {
+        return error_range(
+            args[1].span(),
+            args[2].span(),
+            format!("Length mismatch ({} and {})", a.len(), b.len()),
+        );
+    }
+
+    let mut res = TokenStream::new();
+    for (a, b) in a.into_iter().zip(b) {
+        res.extend(fill_markers(tmp.stream(), &[a, b]));
+    }
+    res
+}
+
+/// Gets exactly `N` comma separated string literals.
+fn get_str_args<const N: usize>(
+    input: TokenStream,
+    pos: Span,
+) -> Result<[String; N], TokenStream> {
+    let args: [TokenTree; N] = get_args(input, pos)?;
+    let mut res = Vec::with_capacity(N);
+    for a in args {
+        match get_str_lit(a.clone()) {
+            Some(s) => res.push(s.into_owned()),
+            None => return Err(error_at(a.span(), "Expected string literal")),
+        }
+    }
+    Ok(res.try_into().unwrap())
+}
+
+pub fn trim_start_matches(input: TokenStream, pos: Span) -> TokenStream {
+    let [s, pat] = match get_str_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(
+        s.trim_start_matches(&pat),
+    ))]);
+    r
+}
+
+pub fn trim_end_matches(input: TokenStream, pos: Span) -> TokenStream {
+    let [s, pat] = match get_str_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(
+        s.trim_end_matches(&pat),
+    ))]);
+    r
+}
+
+/// Gets the character from string literal with single character.
+fn get_char_str(tt: TokenTree) -> Option<char> {
+    let s = get_str_lit(tt)?;
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+pub fn center(input: TokenStream, pos: Span) -> TokenStream {
+    let [s, width, fill] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let Some(s) = get_str_lit(s.clone()) else {
+        return error_at(s.span(), "Expected string literal");
+    };
+    let Some(w) = get_uint_lit(width.clone()) else {
+        return error_at(width.span(), "Expected integer literal");
+    };
+    if let Err(e) = check_repeat(w, width.span()) {
+        return e;
+    }
+    let Some(f) = get_char_str(fill.clone()) else {
+        return error_at(
+            fill.span(),
+            "Expected string literal with single character",
+        );
+    };
+
+    let len = s.chars().count();
+    let pad = (w as usize).saturating_sub(len);
+    let left: String = std::iter::repeat(f).take(pad / 2).collect();
+    let right: String = std::iter::repeat(f).take(pad - pad / 2).collect();
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&(left + &s + &right)))]);
+    r
+}
+
+pub fn json_escape(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let s = match get_str_lit(t.clone()) {
+        Some(s) => s,
+        None => return error_at(t.span(), "Expected string literal"),
+    };
+
+    let mut res = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => res += "\\\"",
+            '\\' => res += "\\\\",
+            '\n' => res += "\\n",
+            '\r' => res += "\\r",
+            '\t' => res += "\\t",
+            '\u{8}' => res += "\\b",
+            '\u{c}' => res += "\\f",
+            c if c < ' ' => res += &format!("\\u{:04x}", c as u32),
+            c => res.push(c),
+        }
+    }
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&res))]);
+    r
+}
+
+/// Maximum number of repetitions (or generated items) in macros that repeat
+/// something. Larger counts are most likely typos that would hang the compiler
+/// or make it run out of memory.
+const MAX_REPEAT: u128 = 1 << 16;
+
+/// Fails if the count `n` is larger than [`MAX_REPEAT`].
+fn check_repeat(n: u128, span: Span) -> Result<(), TokenStream> {
+    if n <= MAX_REPEAT {
+        return Ok(());
+    }
+    Err(error_at(
+        span,
+        format!("Count {n} is too large, the maximum is {MAX_REPEAT}"),
+    ))
+}
+
+pub fn range_rev(input: TokenStream, pos: Span) -> TokenStream {
+    let [(start, _), (end, _)] = match get_int_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    if start > end {
+        if let Err(e) = check_repeat(start.abs_diff(end), pos) {
+            return e;
+        }
+    }
+
+    let mut res = TokenStream::new();
+    for (n, v) in (end..start).rev().enumerate() {
+        if n != 0 {
+            res.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        }
+        res.extend([TokenTree::Literal(Literal::i128_unsuffixed(v))]);
+    }
+    res
+}
+
+pub fn type_param_names(input: TokenStream, pos: Span) -> TokenStream {
+    let mut args = split_args(input).into_iter();
+    let n = match args.next() {
+        Some(n) if !n.is_empty() => n,
+        _ => return error_at(pos, "Expected count"),
+    };
+    let prefix = match args.next() {
+        Some(p) => {
+            let s = match p.as_slice() {
+                [t] => get_str_lit(t.clone()),
+                _ => None,
+            };
+            match s {
+                Some(s) if is_ident(&format!("{s}0")) => Some(s),
+                _ => {
+                    return error_at(
+                        p.first().map_or(pos, |t| t.span()),
+                        "Expected string literal with identifier",
+                    )
+                }
+            }
+        }
+        None => None,
+    };
+    if let Some(t) = args.next().and_then(|a| a.into_iter().next()) {
+        return error_range(pos, t.span(), "Macro takes at most 2 arguments");
+    }
+    let Some(cnt) = get_int(&n).and_then(|n| usize::try_from(n).ok()) else {
+        return error_at(n[0].span(), "Expected non-negative integer literal");
+    };
+    if prefix.is_none() && cnt > 26 {
+        return error_at(
+            n[0].span(),
+            "There are only 26 letters, use prefix for more parameters",
+        );
+    }
+    if let Err(e) = check_repeat(cnt as u128, n[0].span()) {
+        return e;
+    }
+
+    let mut res = TokenStream::new();
+    for i in 0..cnt {
+        if i != 0 {
+            res.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        }
+        let name = match &prefix {
+            Some(p) => format!("{p}{i}"),
+            None => char::from(b'A' + i as u8).to_string(),
+        };
+        res.extend([TokenTree::Ident(Ident::new(&name, Span::call_site()))]);
+    }
+    res
+}
+
+pub fn strip_comments(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let s = match get_str_lit(t.clone()) {
+        Some(s) => s,
+        None => return error_at(t.span(), "Expected string literal"),
+    };
+
+    let mut res = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('/', Some('/')) => {
+                while chars.next_if(|c| *c != '\n').is_some() {}
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                // Block comments may be nested.
+                let mut depth = 1;
+                while depth != 0 {
+                    match (chars.next(), chars.peek()) {
+                        (Some('/'), Some('*')) => {
+                            chars.next();
+                            depth += 1;
+                        }
+                        (Some('*'), Some('/')) => {
+                            chars.next();
+                            depth -= 1;
+                        }
+                        (None, _) => break,
+                        _ => {}
+                    }
+                }
+            }
+            _ => res.push(c),
+        }
+    }
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&res))]);
+    r
+}
+
+pub fn char_range(input: TokenStream, pos: Span) -> TokenStream {
+    let [start, end] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let get_char = |t: &TokenTree| match litrs::CharLit::try_from(t.clone()) {
+        Ok(c) => Ok(c.value()),
+        Err(_) => Err(error_at(t.span(), "Expected char literal")),
+    };
+    let (s, e) = match (get_char(&start), get_char(&end)) {
+        (Ok(s), Ok(e)) => (s, e),
+        (Err(e), _) | (_, Err(e)) => return e,
+    };
+    if s > e {
+        return error_range(
+            start.span(),
+            end.span(),
+            "Start of the range is larger than the end",
+        );
+    }
+
+    let mut res = TokenStream::new();
+    for (n, c) in (s..=e).enumerate() {
+        if n != 0 {
+            res.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        }
+        res.extend([TokenTree::Literal(Literal::character(c))]);
+    }
+    res
+}
+
+pub fn literal_concat(input: TokenStream, pos: Span) -> TokenStream {
+    let ints: Option<Vec<_>> =
+        input.clone().into_iter().map(get_uint_lit).collect();
+
+    let lit = match ints {
+        Some(ints) if !ints.is_empty() => {
+            let digits: String = ints.iter().map(|i| i.to_string()).collect();
+            match digits.parse::<u128>() {
+                Ok(n) => Literal::u128_unsuffixed(n),
+                Err(_) => return error_at(pos, "Integer is too large"),
+            }
+        }
+        _ => Literal::string(&token_concat(input, false)),
+    };
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(lit)]);
+    res
+}
+
+pub fn repeat_tail(input: TokenStream, pos: Span) -> TokenStream {
+    let mut i = input.into_iter();
+    let (n, _) = match get_count(&mut i, pos) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    let Some(first) = i.next() else {
+        return error_at(pos, "Expected tokens");
+    };
+    let rest: TokenStream = i.collect();
+
+    let mut res = TokenStream::new();
+    res.extend([first]);
+    for _ in 0..n {
+        res.extend(rest.clone());
+    }
+    res
+}
+
+pub fn indent(input: TokenStream, pos: Span) -> TokenStream {
+    let (s, prefix, all) = if split_args(input.clone()).len() == 3 {
+        let [s, p, f] = match get_args(input, pos) {
+            Ok(a) => a,
+            Err(e) => return e,
+        };
+        if get_str_lit(f.clone()).as_deref() != Some("all") {
+            return error_at(f.span(), "Expected \"all\"");
+        }
+        (s, p, true)
+    } else {
+        match get_args(input, pos) {
+            Ok([s, p]) => (s, p, false),
+            Err(e) => return e,
+        }
+    };
+    let Some(s) = get_str_lit(s.clone()) else {
+        return error_at(s.span(), "Expected string literal");
+    };
+    let Some(prefix) = get_str_lit(prefix.clone()) else {
+        return error_at(prefix.span(), "Expected string literal");
+    };
+
+    let (lines, nl) = split_lines(&s);
+    let lines: Vec<_> = lines
+        .into_iter()
+        .map(|l| {
+            if all || !l.trim().is_empty() {
+                format!("{prefix}{l}")
+            } else {
+                l.to_string()
+            }
+        })
+        .collect();
+    let res = join_lines(lines.iter().map(|l| l.as_str()), nl);
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&res))]);
+    r
+}
+
+pub fn replace_nth(input: TokenStream, pos: Span) -> TokenStream {
+    let (n, span, replacement, tokens) = match count_group_rest(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let mut tokens: Vec<_> = tokens.into_iter().collect();
+    if n >= tokens.len() {
+        return error_at(span, format!("Index {n} is out of range"));
+    }
+
+    let mut res = TokenStream::new();
+    res.extend(tokens.drain(..n));
+    res.extend(replacement);
+    res.extend(tokens.into_iter().skip(1));
+    res
+}
+
+pub fn insert_at(input: TokenStream, pos: Span) -> TokenStream {
+    let (n, span, insertion, tokens) = match count_group_rest(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let mut tokens: Vec<_> = tokens.into_iter().collect();
+    if n > tokens.len() {
+        return error_at(span, format!("Index {n} is out of range"));
+    }
+
+    let mut res = TokenStream::new();
+    res.extend(tokens.drain(..n));
+    res.extend(insertion);
+    res.extend(tokens);
+    res
+}
+
+pub fn remove_at(input: TokenStream, pos: Span) -> TokenStream {
+    let mut i = input.into_iter();
+    let (n, span) = match get_count(&mut i, pos) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    let mut tokens: Vec<_> = i.collect();
+    if n >= tokens.len() {
+        return error_at(span, format!("Index {n} is out of range"));
+    }
+    tokens.remove(n);
+
+    tokens.into_iter().collect()
+}
+
+pub fn transpose(input: TokenStream, pos: Span) -> TokenStream {
+    let mut rows = vec![];
+    for t in input {
+        match t {
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis => {
+                rows.push((
+                    g.span(),
+                    g.stream().into_iter().collect::<Vec<_>>(),
+                ))
+            }
+            t => return error_at(t.span(), "Expected group in parenthesis"),
+        }
+    }
+    let len = rows.first().map_or(0, |(_, r)| r.len());
+    if let Some((s, r)) = rows.iter().find(|(_, r)| r.len() != len) {
+        return error_range(
+            pos,
+            *s,
+            format!("Rows have different lengths ({len} and {})", r.len()),
+        );
+    }
+
+    let mut cols = vec![TokenStream::new(); len];
+    for (_, r) in rows {
+        for (c, t) in cols.iter_mut().zip(r) {
+            c.extend([t]);
+        }
+    }
+
+    cols.into_iter()
+        .map(|c| TokenTree::Group(Group::new(Delimiter::Parenthesis, c)))
+        .collect()
+}
+
+pub fn mask(input: TokenStream, pos: Span) -> TokenStream {
+    let args = if split_args(input.clone()).len() == 2 {
+        get_int_args::<2>(input, pos)
+    } else {
+        get_int_args::<1>(input, pos).map(|[b]| [b, (0, pos)])
+    };
+    let [(bits, bspan), (shift, sspan)] = match args {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    if !(0..=128).contains(&bits) {
+        return error_at(bspan, "Bit count must be in range 0..=128");
+    }
+    if shift < 0 || shift > 128 - bits {
+        return error_at(sspan, "The mask doesn't fit into 128 bits");
+    }
+
+    let m = u128::MAX.checked_shr(128 - bits as u32).unwrap_or(0);
+    let m = m.checked_shl(shift as u32).unwrap_or(0);
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::u128_unsuffixed(m))]);
+    res
+}
+
+/// Finds the top level pairs of `<` and `>` (they are not groups, so they
+/// are matched by counting the nesting). `>` that is part of `->` or `=>` is
+/// not counted. Returns the ranges of the tokens including the brackets.
+/// `<` without matching `>` is ignored (e.g. comparison), the pairs after it
+/// are still found.
+fn angle_ranges(tokens: &[TokenTree]) -> Vec<Range<usize>> {
+    let mut res = vec![];
+    let mut start = 0;
+    let mut depth = 0_usize;
+    let mut i = 0;
+    while i < tokens.len() {
+        if let TokenTree::Punct(p) = &tokens[i] {
+            match p.as_char() {
+                '<' => {
+                    if depth == 0 {
+                        start = i;
+                    }
+                    depth += 1;
+                }
+                '>' if depth != 0 && !is_arrow(tokens, i) => {
+                    depth -= 1;
+                    if depth == 0 {
+                        res.push(start..i + 1);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        i += 1;
+        if i == tokens.len() && depth != 0 {
+            // the `<` at `start` has no matching `>`, continue after it
+            i = start + 1;
+            depth = 0;
+        }
+    }
+    res
+}
+
+/// Checks whether the `>` at the index is part of `->` or `=>`.
+fn is_arrow(tokens: &[TokenTree], idx: usize) -> bool {
+    match idx.checked_sub(1).map(|i| &tokens[i]) {
+        Some(TokenTree::Punct(p)) => {
+            matches!(p.as_char(), '-' | '=') && p.spacing() == Spacing::Joint
+        }
+        _ => false,
+    }
+}
+
+pub fn strip_generics(input: TokenStream) -> TokenStream {
+    let tokens: Vec<_> = input.into_iter().collect();
+    let mut res: Vec<TokenTree> = vec![];
+    let mut last = 0;
+    for r in angle_ranges(&tokens) {
+        res.extend_from_slice(&tokens[last..r.start]);
+        // remove `::` of turbofish
+        if res.len() >= 2
+            && res[res.len() - 2..].iter().all(|t| is_punct(t, ':'))
+        {
+            res.truncate(res.len() - 2);
+        }
+        last = r.end;
+    }
+    res.extend_from_slice(&tokens[last..]);
+    res.into_iter().collect()
+}
+
+pub fn angle_group(input: TokenStream) -> TokenStream {
+    let mut tokens: Vec<_> = input.into_iter().collect();
+    let Some(r) = angle_ranges(&tokens).into_iter().next() else {
+        return tokens.into_iter().collect();
+    };
+
+    let rest = tokens.split_off(r.end);
+    let group: TokenStream = tokens.drain(r).collect();
+    tokens.push(TokenTree::Group(Group::new(Delimiter::None, group)));
+    tokens.extend(rest);
+    tokens.into_iter().collect()
+}
+
+pub fn repeat_concat(input: TokenStream, pos: Span) -> TokenStream {
+    let [n, template] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let Some(count) = get_uint_lit(n.clone()) else {
+        return error_at(n.span(), "Expected integer literal");
+    };
+    if let Err(e) = check_repeat(count, n.span()) {
+        return e;
+    }
+    let Some(template) = get_str_lit(template.clone()) else {
+        return error_at(template.span(), "Expected string literal");
+    };
+
+    let res: String = (0..count)
+        .map(|i| template.replace("{i}", &i.to_string()))
+        .collect();
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&res))]);
+    r
+}
+
+pub fn common_prefix(input: TokenStream, pos: Span) -> TokenStream {
+    let strs = match get_str_list(input, pos) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    let prefix = strs.into_iter().reduce(|p, s| {
+        p.chars()
+            .zip(s.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a)
+            .collect()
+    });
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::string(
+        &prefix.unwrap_or_default(),
+    ))]);
+    res
+}
+
+pub fn title_case(input: TokenStream, pos: Span) -> TokenStream {
+    let (s, small) = if split_args(input.clone()).len() == 2 {
+        let [s, f] = match get_args(input, pos) {
+            Ok(a) => a,
+            Err(e) => return e,
+        };
+        if get_str_lit(f.clone()).as_deref() != Some("small") {
+            return error_at(f.span(), "Expected \"small\"");
+        }
+        (s, true)
+    } else {
+        match single_token(input, pos) {
+            Ok(s) => (s, false),
+            Err(e) => return e,
+        }
+    };
+    let Some(s) = get_str_lit(s.clone()) else {
+        return error_at(s.span(), "Expected string literal");
+    };
+
+    let mut res = String::with_capacity(s.len());
+    let mut rest = s.as_ref();
+    let mut first = true;
+    loop {
+        let word_start = rest.len() - rest.trim_start().len();
+        res += &rest[..word_start];
+        rest = &rest[word_start..];
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let word = &rest[..end];
+        rest = &rest[end..];
+        if word.is_empty() {
+            break;
+        }
+
+        let lower = word.to_lowercase();
+        if small && !first && ["a", "an", "the"].contains(&lower.as_str()) {
+            res += &lower;
+        } else {
+            let mut c = word.chars();
+            res.extend(c.next().into_iter().flat_map(char::to_uppercase));
+            res += c.as_str();
+        }
+        first = false;
+    }
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&res))]);
+    r
+}
+
+pub fn assert_count(input: TokenStream, pos: Span) -> TokenStream {
+    let mut i = input.into_iter();
+    let (n, span) = match get_count(&mut i, pos) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    let tokens: TokenStream = i.collect();
+    let cnt = tokens.clone().into_iter().count();
+    if cnt != n {
+        return error_range(
+            pos,
+            span,
+            format!("Expected {n} tokens, got {cnt}"),
+        );
+    }
+
+    tokens
+}
+
+pub fn pick(input: TokenStream, pos: Span) -> TokenStream {
+    let mut i = input.into_iter();
+    let idx = match i.next() {
+        Some(TokenTree::Group(g)) => g.stream(),
+        Some(t) => return error_at(t.span(), "Expected group with indexes"),
+        None => return error_at(pos, "Expected group with indexes"),
+    };
+    check_comma!(i, pos);
+    let tokens: Vec<_> = i.collect();
+
+    let mut res = TokenStream::new();
+    for t in idx {
+        let span = t.span();
+        let Some(n) = get_uint_lit(t) else {
+            return error_at(span, "Expected integer literal");
+        };
+        match usize::try_from(n).ok().and_then(|n| tokens.get(n)) {
+            Some(t) => res.extend([t.clone()]),
+            None => {
+                return error_at(span, format!("Index {n} is out of range"))
+            }
+        }
+    }
+    res
+}
+
+pub fn group_every(input: TokenStream, pos: Span) -> TokenStream {
+    let mut i = input.into_iter();
+    let (n, span) = match get_count(&mut i, pos) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    if n == 0 {
+        return error_at(span, "Count must not be zero");
+    }
+    let Some(d) = i.next() else {
+        return error_at(pos, "Expected delimiter");
+    };
+    let delim = match get_str_lit(d.clone()).as_deref() {
+        Some("()") => Delimiter::Parenthesis,
+        Some("[]") => Delimiter::Bracket,
+        Some("{}") => Delimiter::Brace,
+        Some("") => Delimiter::None,
+        _ => {
+            return error_at(
+                d.span(),
+                "Expected one of \"()\", \"[]\", \"{}\" or \"\"",
+            )
+        }
+    };
+    check_comma!(i, pos);
+    let tokens: Vec<_> = i.collect();
+
+    tokens
+        .chunks(n)
+        .map(|c| {
+            TokenTree::Group(Group::new(delim, c.iter().cloned().collect()))
+        })
+        .collect()
+}
+
+/// Expands to string literal with the value of the environment variable. If
+/// the variable is not set, expands to the contents of the file instead. The
+/// path of the file is relative to `CARGO_MANIFEST_DIR`.
+///
+/// # Examples
+/// ```
+/// use place_macro_core::env_or_file;
+/// use proc_macro2::{Span, TokenStream};
+///
+/// let input: TokenStream =
+///     r#""PLACE_MACRO_VERSION", "Cargo.toml""#.parse().unwrap();
+///
+/// std::env::set_var("PLACE_MACRO_VERSION", "1.0");
+/// let res = env_or_file(input.clone(), Span::call_site());
+/// assert_eq!(res.to_string(), r#""1.0""#);
+///
+/// std::env::remove_var("PLACE_MACRO_VERSION");
+/// let res = env_or_file(input, Span::call_site());
+/// assert!(res.to_string().starts_with(r#""[package]"#));
+/// ```
+#[cfg(feature = "std")]
+pub fn env_or_file(input: TokenStream, pos: Span) -> TokenStream {
+    let [var, path] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let Some(var) = get_str_lit(var.clone()) else {
+        return error_at(var.span(), "Expected string literal");
+    };
+    let Some(path) = get_str_lit(path.clone()) else {
+        return error_at(path.span(), "Expected string literal");
+    };
+
+    let value = match std::env::var(var.as_ref()) {
+        Ok(v) => v,
+        Err(e) => {
+            let mut file = std::path::PathBuf::new();
+            if let Some(dir) = std::env::var_os("CARGO_MANIFEST_DIR") {
+                file.push(dir);
+            }
+            file.push(path.as_ref());
+            match std::fs::read_to_string(&file) {
+                Ok(v) => v,
+                Err(fe) => {
+                    return error_at(
+                        pos,
+                        format!(
+                            "Failed to read environment variable `{var}` \
+                            ({e}) and file `{}` ({fe})",
+                            file.display()
+                        ),
+                    )
+                }
+            }
+        }
+    };
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::string(&value))]);
+    res
+}
+
+/// Replaces the identifier `marker` (even inside groups) with the tokens.
+fn replace_marker(
+    template: TokenStream,
+    marker: &str,
+    tokens: &TokenStream,
+) -> TokenStream {
+    let mut res = TokenStream::new();
+    for t in template {
+        match t {
+            TokenTree::Group(g) => {
+                let mut n = Group::new(
+                    g.delimiter(),
+                    replace_marker(g.stream(), marker, tokens),
+                );
+                n.set_span(g.span());
+                res.extend([TokenTree::Group(n)]);
+            }
+            TokenTree::Ident(i) if i == marker => res.extend(tokens.clone()),
+            t => res.extend([t]),
+        }
+    }
+    res
+}
+
+pub fn for_each(input: TokenStream, pos: Span) -> TokenStream {
+    let mut i = input.into_iter();
+    let template = match i.next() {
+        Some(TokenTree::Group(g)) => g.stream(),
+        Some(t) => return error_at(t.span(), "Expected group with template"),
+        None => return error_at(pos, "Expected group with template"),
+    };
+    check_comma!(i, pos);
+
+    let mut res = TokenStream::new();
+    for t in i {
+        let TokenTree::Group(g) = t else {
+            return error_at(t.span(), "Expected group");
+        };
+        res.extend(replace_marker(template.clone(), "__x__", &g.stream()));
+    }
+    res
+}
+
+pub fn clean_doc(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let s = match get_str_lit(t.clone()) {
+        Some(s) => s,
+        None => return error_at(t.span(), "Expected string literal"),
+    };
+
+    let (lines, _) = split_lines(&s);
+    let mut lines: Vec<_> = lines.into_iter().map(str::trim_end).collect();
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    let start = lines.iter().take_while(|l| l.is_empty()).count();
+    let lines = &lines[start..];
+    let indent = lines
+        .iter()
+        .filter(|l| !l.is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or_default();
+    let res = join_lines(
+        lines
+            .iter()
+            .map(|l| l.get(indent..).unwrap_or(l.trim_start())),
+        false,
+    );
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&res))]);
+    r
+}
+
+pub fn escape_unicode(input: TokenStream, pos: Span) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let s = match get_str_lit(t.clone()) {
+        Some(s) => s,
+        None => return error_at(t.span(), "Expected string literal"),
+    };
+
+    let mut res = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_ascii() {
+            res.push(c);
+        } else {
+            res.extend(c.escape_unicode());
+        }
+    }
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&res))]);
+    r
+}
+
+/// Puts each of the streams into group in parenthesis.
+fn paren_groups<const N: usize>(streams: [TokenStream; N]) -> TokenStream {
+    streams
+        .into_iter()
+        .map(|s| TokenTree::Group(Group::new(Delimiter::Parenthesis, s)))
+        .collect()
+}
+
+pub fn split_first(input: TokenStream) -> TokenStream {
+    let mut i = input.into_iter();
+    let first = i.next().into_iter().collect();
+    paren_groups([first, i.collect()])
+}
+
+pub fn split_last(input: TokenStream) -> TokenStream {
+    paren_groups([start(input.clone()), last(input)])
+}
+
+pub fn partition(input: TokenStream, pos: Span) -> TokenStream {
+    let mut i = input.into_iter();
+    let sep = match i.next() {
+        Some(t @ (TokenTree::Ident(_) | TokenTree::Punct(_))) => t,
+        Some(t) => {
+            return error_at(t.span(), "Expected identifier or punctuation")
+        }
+        None => return error_at(pos, "Expected separator"),
+    };
+    let is_sep = |t: &TokenTree| match (t, &sep) {
+        (TokenTree::Ident(a), TokenTree::Ident(b)) => a == b,
+        (TokenTree::Punct(a), TokenTree::Punct(b)) => {
+            a.as_char() == b.as_char()
+        }
+        _ => false,
+    };
+    check_comma!(i, pos);
+
+    let before = i.by_ref().take_while(|t| !is_sep(t)).collect();
+    paren_groups([before, i.collect()])
+}
+
+pub fn count_char(input: TokenStream, pos: Span) -> TokenStream {
+    let [s, c] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let Some(s) = get_str_lit(s.clone()) else {
+        return error_at(s.span(), "Expected string literal");
+    };
+    let Some(c) = get_char_str(c.clone()) else {
+        return error_at(
+            c.span(),
+            "Expected string literal with single character",
+        );
+    };
+
+    let mut res = TokenStream::new();
+    res.extend([TokenTree::Literal(Literal::usize_unsuffixed(
+        s.matches(c).count(),
+    ))]);
+    res
+}
+
+pub fn cycle(input: TokenStream, pos: Span) -> TokenStream {
+    let mut i = input.into_iter();
+    let (n, span) = match get_count(&mut i, pos) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    if let Err(e) = check_repeat(n as u128, span) {
+        return e;
+    }
+    let tokens: Vec<_> = i.collect();
+    if tokens.is_empty() && n != 0 {
+        return error_at(pos, "Expected tokens to repeat");
+    }
+
+    let mut res = TokenStream::new();
+    res.extend(tokens.into_iter().cycle().take(n));
+    res
+}
+
+pub fn if_present(input: TokenStream, pos: Span) -> TokenStream {
+    let [maybe, then] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let (TokenTree::Group(maybe), TokenTree::Group(then)) = (&maybe, &then)
+    else {
+        let t = if matches!(maybe, TokenTree::Group(_)) {
+            then
+        } else {
+            maybe
+        };
+        return error_at(t.span(), "Expected group");
+    };
+
+    if is_empty_stream(&maybe.stream()) {
+        TokenStream::new()
+    } else {
+        replace_marker(then.stream(), "__it__", &maybe.stream())
+    }
+}
+
+pub fn numbered_idents(input: TokenStream, pos: Span) -> TokenStream {
+    let [base, n] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let TokenTree::Ident(base) = base else {
+        return error_at(base.span(), "Expected identifier");
+    };
+    let Some(count) = get_uint_lit(n.clone()) else {
+        return error_at(n.span(), "Expected integer literal");
+    };
+    if let Err(e) = check_repeat(count, n.span()) {
+        return e;
+    }
+    let name = base.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name);
+
+    let mut res = TokenStream::new();
+    for i in 0..count {
+        if i != 0 {
+            res.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        }
+        res.extend([TokenTree::Ident(Ident::new(
+            &format!("{name}{i}"),
+            base.span(),
+        ))]);
+    }
+    res
+}
+
+/// Picks the string from the comma separated string literals by comparing
+/// their lengths (in characters). The first one wins on ties.
+fn pick_by_len(input: TokenStream, pos: Span, longer: bool) -> TokenStream {
+    let strs = match get_str_list(input, pos) {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    let mut res: Option<(usize, String)> = None;
+    for s in strs {
+        let len = s.chars().count();
+        let better = match &res {
+            Some((l, _)) => (len > *l && longer) || (len < *l && !longer),
+            None => true,
+        };
+        if better {
+            res = Some((len, s));
+        }
+    }
+    let Some((_, s)) = res else {
+        return error_at(pos, "Expected string literal");
+    };
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&s))]);
+    r
+}
+
+pub fn longest(input: TokenStream, pos: Span) -> TokenStream {
+    pick_by_len(input, pos, true)
+}
+
+pub fn shortest(input: TokenStream, pos: Span) -> TokenStream {
+    pick_by_len(input, pos, false)
+}
+
+/// Replaces all occurences of `from` with `to` in single string literal.
+fn replace_preset(
+    from: &str,
+    to: &str,
+    input: TokenStream,
+    pos: Span,
+) -> TokenStream {
+    let t = match single_token(input, pos) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let Some(s) = get_str_lit(t.clone()) else {
+        return error_at(t.span(), "Expected string literal");
+    };
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(&s.replace(from, to)))]);
+    r
+}
+
+pub fn kebab_to_snake(input: TokenStream, pos: Span) -> TokenStream {
+    replace_preset("-", "_", input, pos)
+}
+
+pub fn snake_to_kebab(input: TokenStream, pos: Span) -> TokenStream {
+    replace_preset("_", "-", input, pos)
+}
+
+pub fn path_to_snake(input: TokenStream, pos: Span) -> TokenStream {
+    replace_preset("::", "_", input, pos)
+}
+
+pub fn trim_char(input: TokenStream, pos: Span) -> TokenStream {
+    let [s, c] = match get_args(input, pos) {
+        Ok(a) => a,
+        Err(e) => return e,
+    };
+    let Some(s) = get_str_lit(s.clone()) else {
+        return error_at(s.span(), "Expected string literal");
+    };
+    let Some(c) = get_char_str(c.clone()) else {
+        return error_at(
+            c.span(),
+            "Expected string literal with single character",
+        );
+    };
+
+    let mut r = TokenStream::new();
+    r.extend([TokenTree::Literal(Literal::string(s.trim_matches(c)))]);
+    r
+}
+
+pub fn place(input: TokenStream) -> TokenStream {
+    let mut res = TokenStream::new();
+    match place_into(input, &mut res) {
+        Ok(()) => res,
+        Err(e) => e,
+    }
+}
+
+/// Same as `place`, but the top level results are appended to `out` as soon
+/// as they are available instead of collecting the whole output first. This
+/// reduces the peak memory usage for very large inputs.
+///
+/// If there is error, the error tokens are returned and `out` may contain
+/// part of the output.
+///
+/// # Examples
+/// ```
+/// use place_macro_core::{place, place_into};
+/// use proc_macro2::TokenStream;
+///
+/// let input: TokenStream = "
+///     let __identifier__(a b) = __string__(c d);
+///     const __identifier__(X Y): &str = __stringify__(1 + 2);
+///     __ignore__ __ref__(a) __set__(a __string__(x y)) __ref__(a)
+/// "
+/// .repeat(1000)
+/// .parse()
+/// .unwrap();
+///
+/// let mut out: TokenStream = "let prefix = 5;".parse().unwrap();
+/// place_into(input.clone(), &mut out).unwrap();
+/// assert_eq!(
+///     out.to_string(),
+///     format!("let prefix = 5 ; {}", place(input))
+/// );
+///
+/// let input: TokenStream = "foo __dollar__".parse().unwrap();
+/// let mut out = TokenStream::new();
+/// place_into(input, &mut out).unwrap();
+/// assert_eq!(out.to_string(), "foo $");
+///
+/// let input: TokenStream = "a __string__(b) __ignore__ c".parse().unwrap();
+/// let mut out = TokenStream::new();
+/// let err = place_into(input.clone(), &mut out).unwrap_err();
+/// assert_eq!(err.to_string(), place(input).to_string());
+/// ```
+pub fn place_into(
+    input: TokenStream,
+    out: &mut TokenStream,
+) -> Result<(), TokenStream> {
+    let mut input: Vec<(_, Option<Macro>, _)> =
+        vec![(input.into_iter(), None, None)];
+    // output of the nested groups and macros, top level output goes directly
+    // to `out`
+    let mut res: Vec<TokenStream> = vec![];
+    let mut state = PlaceState::default();
+
+    while let Some((i, m, g)) = input.last_mut() {
+        let t = match (i.next(), m) {
+            (Some(t), _) => t,
+            (_, m) => {
+                if let Some(m) = m {
+                    let t = res.pop().expect("1");
+                    current(&mut res, out).extend(m.invoke(t, &mut state));
+                } else if let Some((d, span)) = g {
+                    let t = res.pop().expect("2");
+                    current(&mut res, out).extend([regroup(*d, t, *span)])
+                }
+                input.pop();
+                continue;
+            }
+        };
+
+        let id = match t {
+            TokenTree::Group(g) => {
+                input.push((
+                    g.stream().into_iter(),
+                    None,
+                    Some((g.delimiter(), g.span())),
+                ));
+                res.push(TokenStream::new());
+                continue;
+            }
+            TokenTree::Ident(id) => id,
+            t => {
+                current(&mut res, out).extend([t]);
+                continue;
+            }
+        };
+
+        let name = id.to_string();
+        let m = match Macro::from_name(&name, id.span()) {
+            None => {
+                current(&mut res, out).extend([TokenTree::Ident(id)]);
+                continue;
+            }
+            Some(Macro::Dollar) => {
+                current(&mut res, out).extend(dollar(TokenStream::new()));
+                continue;
+            }
+            Some(m) => m,
+        };
+
+        let g = match i.next() {
+            Some(TokenTree::Group(g)) => g,
+            Some(TokenTree::Ident(id)) => {
+                if !matches!(m, Macro::Ignore) {
+                    return Err(error_at(id.span(), "Expected '('"));
+                }
+
+                let iname = id.to_string();
+                if let Some(m) = Macro::from_name(&iname, id.span()) {
+                    if matches!(m, Macro::Dollar) {
+                        continue;
+                    }
+                } else {
+                    return Err(error_at(
+                        id.span(),
+                        "Expected '(' or builtin macro",
+                    ));
+                }
+
+                let mut pos = id.span();
+                if let Some(g) = i.next() {
+                    if let TokenTree::Group(g) = g {
+                        let l = input.pop().unwrap();
+                        let mut s = g.stream();
+                        s.extend(l.0);
+                        input.push((s.into_iter(), l.1, l.2));
+                        continue;
+                    } else {
+                        pos = g.span();
+                    }
+                }
+
+                return Err(error_at(pos, "Expected '('"));
+            }
+            Some(t) => {
+                if matches!(m, Macro::Ignore) {
+                    return Err(error_at(
+                        t.span(),
+                        "Expected '(' or builtin macro",
+                    ));
+                }
+                return Err(error_at(t.span(), "Expected '('"));
+            }
+            None => {
+                if matches!(m, Macro::Ignore) {
+                    return Err(error_at(
+                        id.span(),
+                        "Expected '(' or builtin macro after `__ignore__`",
+                    ));
                 }
+                return Err(error_at(
+                    id.span(),
+                    "Expected '(' after builtin macro",
+                ));
+            }
+        };
+
+        if matches!(m, Macro::Identity) {
+            current(&mut res, out).extend(regroup_all(g.stream()))
+        } else {
+            input.push((g.stream().into_iter(), Some(m), None));
+            res.push(TokenStream::new());
+        }
+    }
+
+    Ok(())
+}
+
+/// Gets the stream to which the current output of `place_into` goes.
+fn current<'a>(
+    res: &'a mut [TokenStream],
+    out: &'a mut TokenStream,
+) -> &'a mut TokenStream {
+    res.last_mut().unwrap_or(out)
+}
+
+/// Creates group from the tokens that were inside group in the input of
+/// `place`.
+///
+/// Rustc ignores invisible groups (`Delimiter::None`) created by proc macros,
+/// so invisible groups from macro metavariables would lose their meaning (e.g.
+/// `$e * 3` where `$e` is `1 + 2` would be `1 + 2 * 3`). Because of that,
+/// invisible groups that look like expression with binary operator are
+/// replaced with parenthesis.
+fn regroup(delim: Delimiter, stream: TokenStream, span: Span) -> TokenTree {
+    let delim = if delim == Delimiter::None && is_binary_expr(&stream) {
+        Delimiter::Parenthesis
+    } else {
+        delim
+    };
+    spanned(TokenTree::Group(Group::new(delim, stream)), span)
+}
+
+/// Recursively applies `regroup` to all the groups in the tokens.
+fn regroup_all(input: TokenStream) -> TokenStream {
+    input
+        .into_iter()
+        .map(|t| match t {
+            TokenTree::Group(g) => {
+                regroup(g.delimiter(), regroup_all(g.stream()), g.span())
+            }
+            t => t,
+        })
+        .collect()
+}
+
+/// Checks whether the tokens look like expression that contains binary
+/// operator at the top level (so that its precedence may matter).
+///
+/// The check is only heuristic: tokens that start like item, statement or
+/// type (`#`, `pub`, `fn`, `let`, `dyn`, ...) or contain `;` are not
+/// expressions. Operators inside `<` and `>` are ignored so that generic
+/// types are not considered expressions. The operator `|` is also ignored
+/// because it is more common in patterns than in expressions.
+fn is_binary_expr(stream: &TokenStream) -> bool {
+    const NOT_EXPR: &[&str] = &[
+        "pub",
+        "fn",
+        "struct",
+        "enum",
+        "union",
+        "impl",
+        "trait",
+        "mod",
+        "use",
+        "const",
+        "static",
+        "type",
+        "let",
+        "extern",
+        "dyn",
+        "macro_rules",
+    ];
+
+    let tokens: Vec<_> = stream.clone().into_iter().collect();
+    match tokens.first() {
+        Some(TokenTree::Ident(i)) if NOT_EXPR.contains(&&*i.to_string()) => {
+            return false
+        }
+        Some(TokenTree::Punct(p)) if p.as_char() == '#' => return false,
+        None => return false,
+        _ => {}
+    }
+
+    let mut angles = 0;
+    let mut res = false;
+    // the first token is skipped, operator there is unary
+    for (n, t) in tokens.iter().enumerate().skip(1) {
+        let p = match t {
+            TokenTree::Punct(p) => p,
+            _ => continue,
+        };
+        let prev = match &tokens[n - 1] {
+            TokenTree::Punct(p) if p.spacing() == Spacing::Joint => {
+                Some(p.as_char())
             }
             _ => None,
+        };
+        let next = match (p.spacing(), tokens.get(n + 1)) {
+            (Spacing::Joint, Some(TokenTree::Punct(p))) => Some(p.as_char()),
+            _ => None,
+        };
+
+        match (prev, p.as_char(), next) {
+            (_, ';', _) => return false,
+            // `->`, `=>`, `<=`, `>=` and shifts outside of generics
+            (Some('-' | '='), '>', _) | (_, '-' | '=', Some('>')) => {}
+            (_, '<' | '>', Some('=')) | (Some('<' | '>'), '=', _) => {}
+            (_, '<', Some('<')) | (Some('<'), '<', _) => {}
+            (_, '>', Some('>')) | (Some('>'), '>', _) if angles == 0 => {}
+            (_, '<', _) => angles += 1,
+            (_, '>', _) => angles -= 1,
+            _ if angles != 0 => {}
+            (_, '+' | '-' | '*' | '/' | '%' | '^' | '&', _) => res = true,
+            (Some('.'), '.', _) | (Some('=' | '!'), '=', _) => res = true,
+            _ => {}
+        }
+    }
+
+    res && angles == 0
+}
+
+/// State shared by all the macros in single invocation of `place`.
+#[derive(Default)]
+struct PlaceState {
+    /// Keys used by the macro `__once__`.
+    once: HashSet<String>,
+    /// Tokens stored by the macro `__set__`.
+    vars: HashMap<String, TokenStream>,
+}
+
+#[derive(Clone, Copy)]
+enum Macro {
+    Ignore,
+    Identity,
+    Dollar,
+    String,
+    Head,
+    Tail,
+    Start,
+    Last,
+    Reverse,
+    Identifier,
+    Stringify,
+    ReplaceNewline(Span),
+    StrReplace(Span),
+    ToCase(Case, Span),
+    WithSpanOf(Span),
+    AssertIdent(Span),
+    AssertLiteral(Span),
+    EscapeIdent(Span),
+    StringTyped,
+    FirstIdent,
+    CollectIdents,
+    Comment(Span),
+    Debug(Span),
+    Note(Span),
+    CompileError(Span),
+    Once(Span),
+    Set(Span),
+    Ref(Span),
+    Apply(Span),
+    LiteralKind(Span),
+    QuoteEach,
+    PathFirst,
+    PathLast,
+    JoinPath,
+    #[cfg(feature = "std")]
+    IfFeature(Span),
+    StringifyPretty,
+    UniqueLines(Span),
+    SortLines(Span),
+    #[cfg(feature = "syn")]
+    ExpandAndParse(Span),
+    TypeParams(Span),
+    StripBounds(Span),
+    Ordinal(Span),
+    Plural(Span),
+    Singular(Span),
+    RawIdentifier,
+    Byte(Span),
+    ToAscii(Span),
+    LiteralValue(Span),
+    Clamp(Span),
+    EscapeBraces(Span),
+    SelectIndex(Span),
+    RepeatSep(Span),
+    MapStr(Span),
+    ConstEval(Span),
+    PadTokens(Span),
+    WithDefault(Span),
+    TrimIdents(Span),
+    IsKeyword(Span),
+    AlignUp(Span),
+    AlignDown(Span),
+    BitWidth(Span),
+    Enumerate,
+    FlattenDeep,
+    Unsuffix(Span),
+    QuoteStr(Span),
+    MaxLen(Span),
+    ToDoc,
+    RepeatGroup(Span),
+    ReversePath,
+    GroupArgs,
+    ZipWith(Span),
+    TrimStartMatches(Span),
+    TrimEndMatches(Span),
+    Center(Span),
+    JsonEscape(Span),
+    RangeRev(Span),
+    TypeParamNames(Span),
+    StripComments(Span),
+    CharRange(Span),
+    LiteralConcat(Span),
+    RepeatTail(Span),
+    Indent(Span),
+    ReplaceNth(Span),
+    InsertAt(Span),
+    RemoveAt(Span),
+    Transpose(Span),
+    Mask(Span),
+    StripGenerics,
+    AngleGroup,
+    RepeatConcat(Span),
+    CommonPrefix(Span),
+    TitleCase(Span),
+    AssertCount(Span),
+    Pick(Span),
+    GroupEvery(Span),
+    #[cfg(feature = "std")]
+    EnvOrFile(Span),
+    ForEach(Span),
+    CleanDoc(Span),
+    EscapeUnicode(Span),
+    SplitFirst,
+    SplitLast,
+    Partition(Span),
+    CountChar(Span),
+    Cycle(Span),
+    IfPresent(Span),
+    NumberedIdents(Span),
+    Longest(Span),
+    Shortest(Span),
+    KebabToSnake(Span),
+    SnakeToKebab(Span),
+    PathToSnake(Span),
+    TrimChar(Span),
+}
+
+impl Macro {
+    fn from_name(s: &str, pos: Span) -> Option<Macro> {
+        let name = s.strip_prefix("__")?.strip_suffix("__")?;
+        match name {
+            "ignore" => Some(Self::Ignore),
+            "identity" | "id" => Some(Self::Identity),
+            "dollar" | "s" => Some(Self::Dollar),
+            "string" | "str" => Some(Self::String),
+            "head" => Some(Self::Head),
+            "tail" => Some(Self::Tail),
+            "start" => Some(Self::Start),
+            "last" => Some(Self::Last),
+            "reverse" => Some(Self::Reverse),
+            "identifier" | "ident" => Some(Self::Identifier),
+            "stringify" | "strfy" => Some(Self::Stringify),
+            "replace_newline" | "repnl" => Some(Self::ReplaceNewline(pos)),
+            "str_replace" | "repstr" => Some(Self::StrReplace(pos)),
+            "with_span_of" => Some(Self::WithSpanOf(pos)),
+            "assert_ident" => Some(Self::AssertIdent(pos)),
+            "assert_literal" => Some(Self::AssertLiteral(pos)),
+            "escape_ident" => Some(Self::EscapeIdent(pos)),
+            "string_typed" => Some(Self::StringTyped),
+            "first_ident" => Some(Self::FirstIdent),
+            "collect_idents" => Some(Self::CollectIdents),
+            "comment" => Some(Self::Comment(pos)),
+            "debug" => Some(Self::Debug(pos)),
+            "note" => Some(Self::Note(pos)),
+            "compile_error" | "err" => Some(Self::CompileError(pos)),
+            "once" => Some(Self::Once(pos)),
+            "set" => Some(Self::Set(pos)),
+            "ref" => Some(Self::Ref(pos)),
+            "apply" => Some(Self::Apply(pos)),
+            "literal_kind" => Some(Self::LiteralKind(pos)),
+            "quote_each" => Some(Self::QuoteEach),
+            "path_first" => Some(Self::PathFirst),
+            "path_last" => Some(Self::PathLast),
+            "join_path" => Some(Self::JoinPath),
+            #[cfg(feature = "std")]
+            "if_feature" => Some(Self::IfFeature(pos)),
+            "stringify_pretty" => Some(Self::StringifyPretty),
+            "unique_lines" => Some(Self::UniqueLines(pos)),
+            "sort_lines" => Some(Self::SortLines(pos)),
+            #[cfg(feature = "syn")]
+            "expand_and_parse" => Some(Self::ExpandAndParse(pos)),
+            "type_params" => Some(Self::TypeParams(pos)),
+            "strip_bounds" => Some(Self::StripBounds(pos)),
+            "screaming" => Some(Self::ToCase(Case::UpperSnake, pos)),
+            "pascal" => Some(Self::ToCase(Case::Pascal, pos)),
+            "camel" => Some(Self::ToCase(Case::Camel, pos)),
+            "snake" => Some(Self::ToCase(Case::Snake, pos)),
+            "kebab" => Some(Self::ToCase(Case::Kebab, pos)),
+            "ordinal" => Some(Self::Ordinal(pos)),
+            "plural" => Some(Self::Plural(pos)),
+            "singular" => Some(Self::Singular(pos)),
+            "raw_identifier" | "raw_ident" => Some(Self::RawIdentifier),
+            "byte" => Some(Self::Byte(pos)),
+            "to_ascii" => Some(Self::ToAscii(pos)),
+            "literal_value" => Some(Self::LiteralValue(pos)),
+            "clamp" => Some(Self::Clamp(pos)),
+            "escape_braces" => Some(Self::EscapeBraces(pos)),
+            "select_index" => Some(Self::SelectIndex(pos)),
+            "repeat_sep" => Some(Self::RepeatSep(pos)),
+            "map_str" => Some(Self::MapStr(pos)),
+            "const_eval" => Some(Self::ConstEval(pos)),
+            "pad_tokens" => Some(Self::PadTokens(pos)),
+            "with_default" => Some(Self::WithDefault(pos)),
+            "trim_idents" => Some(Self::TrimIdents(pos)),
+            "is_keyword" => Some(Self::IsKeyword(pos)),
+            "align_up" => Some(Self::AlignUp(pos)),
+            "align_down" => Some(Self::AlignDown(pos)),
+            "bit_width" => Some(Self::BitWidth(pos)),
+            "enumerate" => Some(Self::Enumerate),
+            "flatten_deep" => Some(Self::FlattenDeep),
+            "unsuffix" => Some(Self::Unsuffix(pos)),
+            "quote_str" | "escape_string" => Some(Self::QuoteStr(pos)),
+            "max_len" => Some(Self::MaxLen(pos)),
+            "to_doc" => Some(Self::ToDoc),
+            "repeat_group" => Some(Self::RepeatGroup(pos)),
+            "reverse_path" => Some(Self::ReversePath),
+            "group_args" => Some(Self::GroupArgs),
+            "zip_with" => Some(Self::ZipWith(pos)),
+            "trim_start_matches" => Some(Self::TrimStartMatches(pos)),
+            "trim_end_matches" => Some(Self::TrimEndMatches(pos)),
+            "center" => Some(Self::Center(pos)),
+            "json_escape" => Some(Self::JsonEscape(pos)),
+            "range_rev" => Some(Self::RangeRev(pos)),
+            "type_param_names" => Some(Self::TypeParamNames(pos)),
+            "strip_comments" => Some(Self::StripComments(pos)),
+            "char_range" => Some(Self::CharRange(pos)),
+            "literal_concat" | "lit_concat" => Some(Self::LiteralConcat(pos)),
+            "repeat_tail" => Some(Self::RepeatTail(pos)),
+            "indent" => Some(Self::Indent(pos)),
+            "replace_nth" => Some(Self::ReplaceNth(pos)),
+            "insert_at" => Some(Self::InsertAt(pos)),
+            "remove_at" => Some(Self::RemoveAt(pos)),
+            "transpose" => Some(Self::Transpose(pos)),
+            "mask" => Some(Self::Mask(pos)),
+            "strip_generics" => Some(Self::StripGenerics),
+            "angle_group" => Some(Self::AngleGroup),
+            "repeat_concat" => Some(Self::RepeatConcat(pos)),
+            "common_prefix" => Some(Self::CommonPrefix(pos)),
+            "title_case" => Some(Self::TitleCase(pos)),
+            "assert_count" => Some(Self::AssertCount(pos)),
+            "pick" => Some(Self::Pick(pos)),
+            "group_every" => Some(Self::GroupEvery(pos)),
+            #[cfg(feature = "std")]
+            "env_or_file" => Some(Self::EnvOrFile(pos)),
+            "for_each" => Some(Self::ForEach(pos)),
+            "clean_doc" => Some(Self::CleanDoc(pos)),
+            "escape_unicode" => Some(Self::EscapeUnicode(pos)),
+            "split_first" => Some(Self::SplitFirst),
+            "split_last" => Some(Self::SplitLast),
+            "partition" => Some(Self::Partition(pos)),
+            "count_char" => Some(Self::CountChar(pos)),
+            "cycle" => Some(Self::Cycle(pos)),
+            "if_present" => Some(Self::IfPresent(pos)),
+            "numbered_idents" => Some(Self::NumberedIdents(pos)),
+            "longest" => Some(Self::Longest(pos)),
+            "shortest" => Some(Self::Shortest(pos)),
+            "kebab_to_snake" => Some(Self::KebabToSnake(pos)),
+            "snake_to_kebab" => Some(Self::SnakeToKebab(pos)),
+            "path_to_snake" => Some(Self::PathToSnake(pos)),
+            "trim_char" | "trim_matches_char" => Some(Self::TrimChar(pos)),
+            n => get_case(n).map(|c| Self::ToCase(c, pos)),
         }
     }
 
-    fn invoke(&self, input: TokenStream) -> TokenStream {
+    fn invoke(
+        &self,
+        input: TokenStream,
+        state: &mut PlaceState,
+    ) -> TokenStream {
         match self {
             Macro::Ignore => ignore(input),
             Macro::Identity => identity(input),
@@ -520,7 +4308,108 @@ impl Macro {
             Macro::Stringify => stringify(input),
             Macro::ReplaceNewline(pos) => replace_newline(input, *pos),
             Macro::StrReplace(pos) => str_replace(input, *pos),
-            Macro::ToCase(pos) => to_case(input, *pos),
+            Macro::ToCase(case, pos) => case_preset(*case, input, *pos),
+            Macro::WithSpanOf(pos) => with_span_of(input, *pos),
+            Macro::AssertIdent(pos) => assert_ident(input, *pos),
+            Macro::AssertLiteral(pos) => assert_literal(input, *pos),
+            Macro::EscapeIdent(pos) => escape_ident(input, *pos),
+            Macro::StringTyped => string_typed(input),
+            Macro::FirstIdent => first_ident(input),
+            Macro::CollectIdents => collect_idents(input),
+            Macro::Comment(pos) => comment(input, *pos),
+            Macro::Debug(pos) => debug(input, *pos),
+            Macro::Note(pos) => note(input, *pos),
+            Macro::CompileError(pos) => compile_error(input, *pos),
+            Macro::Once(pos) => once(input, *pos, &mut state.once),
+            Macro::Set(pos) => set(input, *pos, &mut state.vars),
+            Macro::Ref(pos) => reference(input, *pos, &state.vars),
+            Macro::Apply(pos) => apply(input, *pos),
+            Macro::LiteralKind(pos) => literal_kind(input, *pos),
+            Macro::QuoteEach => quote_each(input),
+            Macro::PathFirst => path_first(input),
+            Macro::PathLast => path_last(input),
+            Macro::JoinPath => join_path(input),
+            #[cfg(feature = "std")]
+            Macro::IfFeature(pos) => if_feature(input, *pos),
+            Macro::StringifyPretty => stringify_pretty(input),
+            Macro::UniqueLines(pos) => unique_lines(input, *pos),
+            Macro::SortLines(pos) => sort_lines(input, *pos),
+            #[cfg(feature = "syn")]
+            Macro::ExpandAndParse(pos) => expand_and_parse(input, *pos),
+            Macro::TypeParams(pos) => type_params(input, *pos),
+            Macro::StripBounds(pos) => strip_bounds(input, *pos),
+            Macro::Ordinal(pos) => ordinal(input, *pos),
+            Macro::Plural(pos) => plural(input, *pos),
+            Macro::Singular(pos) => singular(input, *pos),
+            Macro::RawIdentifier => raw_identifier(input),
+            Macro::Byte(pos) => byte(input, *pos),
+            Macro::ToAscii(pos) => to_ascii(input, *pos),
+            Macro::LiteralValue(pos) => literal_value(input, *pos),
+            Macro::Clamp(pos) => clamp(input, *pos),
+            Macro::EscapeBraces(pos) => escape_braces(input, *pos),
+            Macro::SelectIndex(pos) => select_index(input, *pos),
+            Macro::RepeatSep(pos) => repeat_sep(input, *pos),
+            Macro::MapStr(pos) => map_str(input, *pos),
+            Macro::ConstEval(pos) => const_eval(input, *pos),
+            Macro::PadTokens(pos) => pad_tokens(input, *pos),
+            Macro::WithDefault(pos) => with_default(input, *pos),
+            Macro::TrimIdents(pos) => trim_idents(input, *pos),
+            Macro::IsKeyword(pos) => is_keyword(input, *pos),
+            Macro::AlignUp(pos) => align_up(input, *pos),
+            Macro::AlignDown(pos) => align_down(input, *pos),
+            Macro::BitWidth(pos) => bit_width(input, *pos),
+            Macro::Enumerate => enumerate(input),
+            Macro::FlattenDeep => flatten_deep(input),
+            Macro::Unsuffix(pos) => unsuffix(input, *pos),
+            Macro::QuoteStr(pos) => quote_str(input, *pos),
+            Macro::MaxLen(pos) => max_len(input, *pos),
+            Macro::ToDoc => to_doc(input),
+            Macro::RepeatGroup(pos) => repeat_group(input, *pos),
+            Macro::ReversePath => reverse_path(input),
+            Macro::GroupArgs => group_args(input),
+            Macro::ZipWith(pos) => zip_with(input, *pos),
+            Macro::TrimStartMatches(pos) => trim_start_matches(input, *pos),
+            Macro::TrimEndMatches(pos) => trim_end_matches(input, *pos),
+            Macro::Center(pos) => center(input, *pos),
+            Macro::JsonEscape(pos) => json_escape(input, *pos),
+            Macro::RangeRev(pos) => range_rev(input, *pos),
+            Macro::TypeParamNames(pos) => type_param_names(input, *pos),
+            Macro::StripComments(pos) => strip_comments(input, *pos),
+            Macro::CharRange(pos) => char_range(input, *pos),
+            Macro::LiteralConcat(pos) => literal_concat(input, *pos),
+            Macro::RepeatTail(pos) => repeat_tail(input, *pos),
+            Macro::Indent(pos) => indent(input, *pos),
+            Macro::ReplaceNth(pos) => replace_nth(input, *pos),
+            Macro::InsertAt(pos) => insert_at(input, *pos),
+            Macro::RemoveAt(pos) => remove_at(input, *pos),
+            Macro::Transpose(pos) => transpose(input, *pos),
+            Macro::Mask(pos) => mask(input, *pos),
+            Macro::StripGenerics => strip_generics(input),
+            Macro::AngleGroup => angle_group(input),
+            Macro::RepeatConcat(pos) => repeat_concat(input, *pos),
+            Macro::CommonPrefix(pos) => common_prefix(input, *pos),
+            Macro::TitleCase(pos) => title_case(input, *pos),
+            Macro::AssertCount(pos) => assert_count(input, *pos),
+            Macro::Pick(pos) => pick(input, *pos),
+            Macro::GroupEvery(pos) => group_every(input, *pos),
+            #[cfg(feature = "std")]
+            Macro::EnvOrFile(pos) => env_or_file(input, *pos),
+            Macro::ForEach(pos) => for_each(input, *pos),
+            Macro::CleanDoc(pos) => clean_doc(input, *pos),
+            Macro::EscapeUnicode(pos) => escape_unicode(input, *pos),
+            Macro::SplitFirst => split_first(input),
+            Macro::SplitLast => split_last(input),
+            Macro::Partition(pos) => partition(input, *pos),
+            Macro::CountChar(pos) => count_char(input, *pos),
+            Macro::Cycle(pos) => cycle(input, *pos),
+            Macro::IfPresent(pos) => if_present(input, *pos),
+            Macro::NumberedIdents(pos) => numbered_idents(input, *pos),
+            Macro::Longest(pos) => longest(input, *pos),
+            Macro::Shortest(pos) => shortest(input, *pos),
+            Macro::KebabToSnake(pos) => kebab_to_snake(input, *pos),
+            Macro::SnakeToKebab(pos) => snake_to_kebab(input, *pos),
+            Macro::PathToSnake(pos) => path_to_snake(input, *pos),
+            Macro::TrimChar(pos) => trim_char(input, *pos),
         }
     }
 }
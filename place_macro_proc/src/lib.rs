@@ -21,6 +21,11 @@ pub fn string(input: TokenStream) -> TokenStream {
     place_macro_core::string(input.into()).into()
 }
 
+#[proc_macro]
+pub fn string_typed(input: TokenStream) -> TokenStream {
+    place_macro_core::string_typed(input.into()).into()
+}
+
 #[proc_macro]
 pub fn head(input: TokenStream) -> TokenStream {
     place_macro_core::head(input.into()).into()
@@ -71,6 +76,499 @@ pub fn to_case(input: TokenStream) -> TokenStream {
     place_macro_core::to_case(input.into(), Span::mixed_site()).into()
 }
 
+#[proc_macro]
+pub fn with_span_of(input: TokenStream) -> TokenStream {
+    place_macro_core::with_span_of(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn assert_ident(input: TokenStream) -> TokenStream {
+    place_macro_core::assert_ident(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn assert_literal(input: TokenStream) -> TokenStream {
+    place_macro_core::assert_literal(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn escape_ident(input: TokenStream) -> TokenStream {
+    place_macro_core::escape_ident(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn first_ident(input: TokenStream) -> TokenStream {
+    place_macro_core::first_ident(input.into()).into()
+}
+
+#[proc_macro]
+pub fn collect_idents(input: TokenStream) -> TokenStream {
+    place_macro_core::collect_idents(input.into()).into()
+}
+
+#[proc_macro]
+pub fn comment(input: TokenStream) -> TokenStream {
+    place_macro_core::comment(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn debug(input: TokenStream) -> TokenStream {
+    place_macro_core::debug(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn note(input: TokenStream) -> TokenStream {
+    place_macro_core::note(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn compile_error(input: TokenStream) -> TokenStream {
+    place_macro_core::compile_error(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn apply(input: TokenStream) -> TokenStream {
+    place_macro_core::apply(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn literal_kind(input: TokenStream) -> TokenStream {
+    place_macro_core::literal_kind(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn quote_each(input: TokenStream) -> TokenStream {
+    place_macro_core::quote_each(input.into()).into()
+}
+
+#[proc_macro]
+pub fn path_first(input: TokenStream) -> TokenStream {
+    place_macro_core::path_first(input.into()).into()
+}
+
+#[proc_macro]
+pub fn path_last(input: TokenStream) -> TokenStream {
+    place_macro_core::path_last(input.into()).into()
+}
+
+#[proc_macro]
+pub fn join_path(input: TokenStream) -> TokenStream {
+    place_macro_core::join_path(input.into()).into()
+}
+
+#[cfg(feature = "std")]
+#[proc_macro]
+pub fn if_feature(input: TokenStream) -> TokenStream {
+    place_macro_core::if_feature(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn stringify_pretty(input: TokenStream) -> TokenStream {
+    place_macro_core::stringify_pretty(input.into()).into()
+}
+
+#[proc_macro]
+pub fn unique_lines(input: TokenStream) -> TokenStream {
+    place_macro_core::unique_lines(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn sort_lines(input: TokenStream) -> TokenStream {
+    place_macro_core::sort_lines(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn type_params(input: TokenStream) -> TokenStream {
+    place_macro_core::type_params(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn strip_bounds(input: TokenStream) -> TokenStream {
+    place_macro_core::strip_bounds(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn screaming(input: TokenStream) -> TokenStream {
+    place_macro_core::screaming(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn pascal(input: TokenStream) -> TokenStream {
+    place_macro_core::pascal(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn camel(input: TokenStream) -> TokenStream {
+    place_macro_core::camel(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn snake(input: TokenStream) -> TokenStream {
+    place_macro_core::snake(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn kebab(input: TokenStream) -> TokenStream {
+    place_macro_core::kebab(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn ordinal(input: TokenStream) -> TokenStream {
+    place_macro_core::ordinal(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn plural(input: TokenStream) -> TokenStream {
+    place_macro_core::plural(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn singular(input: TokenStream) -> TokenStream {
+    place_macro_core::singular(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn raw_identifier(input: TokenStream) -> TokenStream {
+    place_macro_core::raw_identifier(input.into()).into()
+}
+
+#[proc_macro]
+pub fn byte(input: TokenStream) -> TokenStream {
+    place_macro_core::byte(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn to_ascii(input: TokenStream) -> TokenStream {
+    place_macro_core::to_ascii(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn literal_value(input: TokenStream) -> TokenStream {
+    place_macro_core::literal_value(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn clamp(input: TokenStream) -> TokenStream {
+    place_macro_core::clamp(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn escape_braces(input: TokenStream) -> TokenStream {
+    place_macro_core::escape_braces(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn select_index(input: TokenStream) -> TokenStream {
+    place_macro_core::select_index(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn repeat_sep(input: TokenStream) -> TokenStream {
+    place_macro_core::repeat_sep(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn map_str(input: TokenStream) -> TokenStream {
+    place_macro_core::map_str(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn const_eval(input: TokenStream) -> TokenStream {
+    place_macro_core::const_eval(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn pad_tokens(input: TokenStream) -> TokenStream {
+    place_macro_core::pad_tokens(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn with_default(input: TokenStream) -> TokenStream {
+    place_macro_core::with_default(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn trim_idents(input: TokenStream) -> TokenStream {
+    place_macro_core::trim_idents(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn is_keyword(input: TokenStream) -> TokenStream {
+    place_macro_core::is_keyword(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn align_up(input: TokenStream) -> TokenStream {
+    place_macro_core::align_up(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn align_down(input: TokenStream) -> TokenStream {
+    place_macro_core::align_down(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn bit_width(input: TokenStream) -> TokenStream {
+    place_macro_core::bit_width(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn enumerate(input: TokenStream) -> TokenStream {
+    place_macro_core::enumerate(input.into()).into()
+}
+
+#[proc_macro]
+pub fn flatten_deep(input: TokenStream) -> TokenStream {
+    place_macro_core::flatten_deep(input.into()).into()
+}
+
+#[proc_macro]
+pub fn unsuffix(input: TokenStream) -> TokenStream {
+    place_macro_core::unsuffix(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn quote_str(input: TokenStream) -> TokenStream {
+    place_macro_core::quote_str(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn max_len(input: TokenStream) -> TokenStream {
+    place_macro_core::max_len(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn to_doc(input: TokenStream) -> TokenStream {
+    place_macro_core::to_doc(input.into()).into()
+}
+
+#[proc_macro]
+pub fn repeat_group(input: TokenStream) -> TokenStream {
+    place_macro_core::repeat_group(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn reverse_path(input: TokenStream) -> TokenStream {
+    place_macro_core::reverse_path(input.into()).into()
+}
+
+#[proc_macro]
+pub fn group_args(input: TokenStream) -> TokenStream {
+    place_macro_core::group_args(input.into()).into()
+}
+
+#[proc_macro]
+pub fn zip_with(input: TokenStream) -> TokenStream {
+    place_macro_core::zip_with(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn trim_start_matches(input: TokenStream) -> TokenStream {
+    place_macro_core::trim_start_matches(input.into(), Span::mixed_site())
+        .into()
+}
+
+#[proc_macro]
+pub fn trim_end_matches(input: TokenStream) -> TokenStream {
+    place_macro_core::trim_end_matches(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn center(input: TokenStream) -> TokenStream {
+    place_macro_core::center(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn json_escape(input: TokenStream) -> TokenStream {
+    place_macro_core::json_escape(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn range_rev(input: TokenStream) -> TokenStream {
+    place_macro_core::range_rev(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn type_param_names(input: TokenStream) -> TokenStream {
+    place_macro_core::type_param_names(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn strip_comments(input: TokenStream) -> TokenStream {
+    place_macro_core::strip_comments(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn char_range(input: TokenStream) -> TokenStream {
+    place_macro_core::char_range(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn literal_concat(input: TokenStream) -> TokenStream {
+    place_macro_core::literal_concat(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn repeat_tail(input: TokenStream) -> TokenStream {
+    place_macro_core::repeat_tail(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn indent(input: TokenStream) -> TokenStream {
+    place_macro_core::indent(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn replace_nth(input: TokenStream) -> TokenStream {
+    place_macro_core::replace_nth(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn insert_at(input: TokenStream) -> TokenStream {
+    place_macro_core::insert_at(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn remove_at(input: TokenStream) -> TokenStream {
+    place_macro_core::remove_at(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn transpose(input: TokenStream) -> TokenStream {
+    place_macro_core::transpose(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn mask(input: TokenStream) -> TokenStream {
+    place_macro_core::mask(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn strip_generics(input: TokenStream) -> TokenStream {
+    place_macro_core::strip_generics(input.into()).into()
+}
+
+#[proc_macro]
+pub fn angle_group(input: TokenStream) -> TokenStream {
+    place_macro_core::angle_group(input.into()).into()
+}
+
+#[proc_macro]
+pub fn repeat_concat(input: TokenStream) -> TokenStream {
+    place_macro_core::repeat_concat(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn common_prefix(input: TokenStream) -> TokenStream {
+    place_macro_core::common_prefix(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn title_case(input: TokenStream) -> TokenStream {
+    place_macro_core::title_case(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn assert_count(input: TokenStream) -> TokenStream {
+    place_macro_core::assert_count(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn pick(input: TokenStream) -> TokenStream {
+    place_macro_core::pick(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn group_every(input: TokenStream) -> TokenStream {
+    place_macro_core::group_every(input.into(), Span::mixed_site()).into()
+}
+
+#[cfg(feature = "std")]
+#[proc_macro]
+pub fn env_or_file(input: TokenStream) -> TokenStream {
+    place_macro_core::env_or_file(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn for_each(input: TokenStream) -> TokenStream {
+    place_macro_core::for_each(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn clean_doc(input: TokenStream) -> TokenStream {
+    place_macro_core::clean_doc(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn escape_unicode(input: TokenStream) -> TokenStream {
+    place_macro_core::escape_unicode(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn split_first(input: TokenStream) -> TokenStream {
+    place_macro_core::split_first(input.into()).into()
+}
+
+#[proc_macro]
+pub fn split_last(input: TokenStream) -> TokenStream {
+    place_macro_core::split_last(input.into()).into()
+}
+
+#[proc_macro]
+pub fn partition(input: TokenStream) -> TokenStream {
+    place_macro_core::partition(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn count_char(input: TokenStream) -> TokenStream {
+    place_macro_core::count_char(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn cycle(input: TokenStream) -> TokenStream {
+    place_macro_core::cycle(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn if_present(input: TokenStream) -> TokenStream {
+    place_macro_core::if_present(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn numbered_idents(input: TokenStream) -> TokenStream {
+    place_macro_core::numbered_idents(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn longest(input: TokenStream) -> TokenStream {
+    place_macro_core::longest(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn shortest(input: TokenStream) -> TokenStream {
+    place_macro_core::shortest(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn kebab_to_snake(input: TokenStream) -> TokenStream {
+    place_macro_core::kebab_to_snake(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn snake_to_kebab(input: TokenStream) -> TokenStream {
+    place_macro_core::snake_to_kebab(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn path_to_snake(input: TokenStream) -> TokenStream {
+    place_macro_core::path_to_snake(input.into(), Span::mixed_site()).into()
+}
+
+#[proc_macro]
+pub fn trim_char(input: TokenStream) -> TokenStream {
+    place_macro_core::trim_char(input.into(), Span::mixed_site()).into()
+}
+
 #[proc_macro]
 pub fn place(input: TokenStream) -> TokenStream {
     place_macro_core::place(input.into()).into()
@@ -17,6 +17,7 @@
 //!   the `place` macro
 //! - `dollar`: expands to dollar sign `$`
 //! - `string`: concats the contents into single string, see the doc
+//! - `string_typed`: same as `string` but keeps the suffixes of numbers
 //! - `identifier`: concats the contents into sintle identifier in the same way
 //!   asstring
 //! - `head`: expands to the first token
@@ -29,6 +30,105 @@
 //!   literal with the given literal
 //! - `str_replace`: replace in string literal
 //! - `to_case`: change the case of a identifier
+//! - `with_span_of`: gives all the tokens the span of another token
+//! - `assert_ident`: expands to the identifier or fails if it is not one
+//! - `assert_literal`: expands to the literal or fails if it is not one
+//! - `escape_ident`: creates valid identifier from any string literal
+//! - `first_ident`: expands to the first identifier, even inside groups
+//! - `collect_idents`: expands to all the identifiers, even inside groups
+//! - `comment`: expands to nothing, accepts only string literal
+//! - `debug`: fails with error showing its input, for debugging
+//! - `note`: shows string literal as compiler warning
+//! - `compile_error`: fails with the given message
+//! - `apply`: invokes macro with name created as in `identifier`
+//! - `literal_kind`: expands to string with the kind of the literal
+//! - `quote_each`: stringifies each token separately
+//! - `path_first`: expands to the first segment of path
+//! - `path_last`: expands to the last segment of path
+//! - `join_path`: joins the segments with `::`
+//! - `if_feature`: chooses tokens based on `CARGO_FEATURE_*` variable, needs
+//!   build script
+//! - `stringify_pretty`: expands to string of the input with nice spacing
+//! - `unique_lines`: removes duplicate lines from string literal
+//! - `sort_lines`: sorts lines in string literal
+//! - `type_params`: expands to names of generic parameters
+//! - `strip_bounds`: removes bounds and defaults from generic parameters
+//! - `screaming`: converts to screaming snake case
+//! - `pascal`: converts to pascal case
+//! - `camel`: converts to camel case
+//! - `snake`: converts to snake case
+//! - `kebab`: converts to kebab case string
+//! - `ordinal`: expands to string with ordinal number (e.g. `"1st"`)
+//! - `plural`: naive english plural of identifier or string
+//! - `singular`: naive english singular of identifier or string
+//! - `raw_identifier`: same as `identifier`, but creates raw identifier
+//! - `byte`: creates byte literal from integer or character
+//! - `to_ascii`: transliterates string literal to ASCII
+//! - `literal_value`: expands to numeric value of char, byte or integer
+//! - `clamp`: clamps integer literal to range
+//! - `escape_braces`: doubles braces in string literal
+//! - `select_index`: expands to contents of group at the index
+//! - `repeat_sep`: repeats tokens with separator between them
+//! - `map_str`: applies operation to each string literal
+//! - `const_eval`: evaluates integer expression in string literal
+//! - `pad_tokens`: pads or truncates tokens to the given length
+//! - `with_default`: expands to first group or the second if the first is empty
+//! - `trim_idents`: removes marker identifier from the start and end
+//! - `is_keyword`: checks whether identifier is keyword
+//! - `align_up`: rounds integer up to power of two alignment
+//! - `align_down`: rounds integer down to power of two alignment
+//! - `bit_width`: number of bits needed to represent integer
+//! - `enumerate`: pairs each token with its index
+//! - `flatten_deep`: removes all groups, even nested
+//! - `unsuffix`: removes type suffix from numeric literal
+//! - `quote_str`: escapes string literal as in rust source
+//! - `max_len`: length of the longest string literal
+//! - `to_doc`: same as `stringify_pretty`, but in backticks
+//! - `repeat_group`: repeats a group
+//! - `reverse_path`: reverses the segments of path
+//! - `group_args`: puts each comma separated argument into parenthesis
+//! - `zip_with`: expands template for each pair of tokens
+//! - `trim_start_matches`: repeatedly removes prefix from string literal
+//! - `trim_end_matches`: repeatedly removes suffix from string literal
+//! - `center`: centers string literal to the given width
+//! - `json_escape`: escapes string literal for use in JSON
+//! - `range_rev`: integers in descending order
+//! - `type_param_names`: generates names for generic parameters
+//! - `strip_comments`: removes comments from string literal
+//! - `char_range`: expands to range of char literals
+//! - `literal_concat`: concatenates literals into integer or string
+//! - `repeat_tail`: repeats all but the first token
+//! - `indent`: prepends prefix to each line of string literal
+//! - `replace_nth`: replaces token at the index
+//! - `insert_at`: inserts tokens at the index
+//! - `remove_at`: removes token at the index
+//! - `transpose`: transposes matrix of tokens
+//! - `mask`: integer with the given number of low bits set
+//! - `strip_generics`: removes generic arguments from path
+//! - `angle_group`: puts `<...>` into invisible group
+//! - `repeat_concat`: repeats string literal with index
+//! - `common_prefix`: common prefix of string literals
+//! - `title_case`: capitalizes words in string literal
+//! - `assert_count`: checks the number of tokens
+//! - `pick`: expands to tokens at the indexes
+//! - `group_every`: splits tokens into groups of the given size
+//! - `env_or_file`: value of environment variable or contents of file
+//! - `for_each`: expands template for each group
+//! - `clean_doc`: removes indentation and blank lines from string literal
+//! - `escape_unicode`: escapes non-ASCII characters in string literal
+//! - `split_first`: splits the first token from the rest
+//! - `split_last`: splits the last token from the rest
+//! - `partition`: splits tokens at separator
+//! - `count_char`: counts character in string literal
+//! - `cycle`: repeats tokens to the given length
+//! - `if_present`: expands template only if the group is not empty
+//! - `numbered_idents`: numbered identifiers
+//! - `longest`: the longest string literal
+//! - `shortest`: the shortest string literal
+//! - `kebab_to_snake`: replaces `-` with `_` in string literal
+//! - `snake_to_kebab`: replaces `_` with `-` in string literal
+//! - `path_to_snake`: replaces `::` with `_` in string literal
+//! - `trim_char`: removes character from both ends of string literal
 //!
 //! ### The macro `place`
 //! Expands the other macros inside in reverse order. The macros inside that
@@ -45,6 +145,41 @@
 //! - `__stringify__` - `__strfy__`
 //! - `__replace_newline__` - `__repnl__`
 //! - `__str_replace__` - `__repstr__`
+//! - `__compile_error__` - `__err__`
+//! - `__raw_identifier__` - `__raw_ident__`
+//! - `__quote_str__` - `__escape_string__`
+//! - `__literal_concat__` - `__lit_concat__`
+//! - `__trim_char__` - `__trim_matches_char__`
+//!
+//! The macro `__ignore__` may also be used without the parenthesis, in that
+//! case it must be followed by other builtin macro:
+//! - `__ignore__(anything)` expands to nothing
+//! - `__ignore__ __dollar__` expands to nothing, the dollar is ignored
+//! - `__ignore__ __macro__(args)` ignores the name of the following macro and
+//!   its parenthesis, so it expands to `args` (macros in `args` are still
+//!   expanded)
+//! - `__ignore__` followed by anything else is an error
+//!
+//! The macro `__once__` is available only inside `place`. It expands to
+//! nothing, but it fails if the same key was already used in the same
+//! invocation of `place` (e.g. `__once__(main)`). This can be used to make
+//! sure that item is generated only once.
+//!
+//! The macros `__set__` and `__ref__` are also available only inside
+//! `place`. `__set__(name, (tokens))` expands to nothing and stores the tokens
+//! under the name, `__ref__(name)` expands to the stored tokens. Because the
+//! arguments are expanded before the macro, `__set__` stores the tokens after
+//! the expansion of the macros inside them and `__ref__` just copies them,
+//! so the expansion is not done again. The value is available to all the
+//! `__ref__` macros that are expanded later in the same `place` (the ones
+//! that come after it, but not the ones in its own arguments) and it can be
+//! overwritten by another `__set__` with the same name.
+//!
+//! With the feature `syn`, the macro `__expand_and_parse__` is available
+//! inside `place`. It expands to its arguments (after the macros inside are
+//! expanded), but fails with error pointing to the problem if the arguments
+//! are not valid items. This way mistakes in the generated code are reported
+//! where they are made, not where the generated code is used.
 //!
 //! The macro `to_case` has simplified usage, the case of the macro call will
 //! determine the case to which convert (e.g. `__ToCase__(my_ident)` will
@@ -55,6 +190,7 @@
 //! - `__ToCase__`
 //! - `__to_case__`
 //! - `__TO_CASE__`
+//! - `__tOcAsE__`
 //!
 //! #### Example
 //! The following passes:
@@ -151,6 +287,16 @@
 //! The last example was a little less readable, but you can see that you can do
 //! a lot with this macro.
 //!
+//! ## Features
+//! - `std` (default): enables the macros that read environment variables or
+//!   files (`if_feature` and `env_or_file`).
+//! - `rich-errors`: errors caused by unexpected token in macro invocation
+//!   (e.g. too many arguments) will span from the macro to the token, instead
+//!   of pointing only to the token. This way you can see which macro
+//!   invocation caused the error.
+//! - `syn`: enables `__expand_and_parse__` in `place` which checks that
+//!   the tokens are valid items.
+//!
 //! ## Links
 //! - **Author:** [BonnyAD9](https://github.com/BonnyAD9)
 //! - **GitHub repository:** [BonnyAD/place_macro](https://github.com/BonnyAD9/place_macro)
@@ -189,15 +335,33 @@ pub use place_macro_proc::dollar;
 /// Converts the input to string literal, literals are interpreted as their
 /// values, punctuation and brackets are ignored and the rest is stringified.
 ///
+/// Numeric literals are converted to their value so their suffix is lost, if
+/// you want to keep it use `string_typed`.
+///
 /// # Examples
 /// ```
 /// use place_macro::string;
 ///
 /// let s = string!("hello" + , ", " {(agent)} ' ' 0x2F);
 /// assert_eq!(s, "hello, agent 47");
+///
+/// let s = string!(5u8 ", " 3.0f32);
+/// assert_eq!(s, "5, 3");
 /// ```
 pub use place_macro_proc::string;
 
+/// Same as `string`, but numeric literals are used exactly as they are
+/// written, so they keep their suffix (and base).
+///
+/// # Examples
+/// ```
+/// use place_macro::string_typed;
+///
+/// let s = string_typed!(5u8 ", " 3.0f32 ", " 0x2F);
+/// assert_eq!(s, "5u8, 3.0f32, 0x2F");
+/// ```
+pub use place_macro_proc::string_typed;
+
 /// Expans to the first token if present
 ///
 /// # Examples
@@ -287,10 +451,57 @@ pub use place_macro_proc::reverse;
 /// let n = identifier!(my + var);
 /// assert_eq!(n, myvar);
 /// ```
+///
+/// Words that are keywords only in some editions (e.g. `gen`) are allowed:
+/// ```
+/// use place_macro::place;
+///
+/// let gen = 1;
+/// let n = place!(__identifier__(ge n));
+/// assert_eq!(n, 1);
+/// ```
+///
+/// The keywords `self`, `Self`, `crate` and `super` cannot be raw
+/// identifiers, so they are created as keywords:
+/// ```
+/// use place_macro::place;
+///
+/// struct A(i32);
+///
+/// impl A {
+///     fn get(&self) -> i32 {
+///         place!(__identifier__(se lf)).0
+///     }
+/// }
+///
+/// assert_eq!(A(5).get(), 5);
+/// ```
+///
+/// It is error if the result is any other keyword in all editions, use
+/// `raw_identifier` if you need to create raw identifier from keyword:
+/// ```compile_fail
+/// use place_macro::identifier;
+///
+/// let a = identifier!(st ruct);
+/// ```
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place! {
+///     __identifier__(st ruct) A;
+/// }
+/// ```
 pub use place_macro_proc::identifier;
 
 /// Should be same to the rust macro stringify
 ///
+/// The input may start with the string literal `"compact"` or `"spaced"`
+/// followed by comma to choose how the tokens are separated:
+/// - `"spaced"` is the default, it is the same as without the flag.
+/// - `"compact"` puts space only between two identifiers or literals, there
+///   are no spaces around punctuation and brackets (e.g. `a + b` is `a+b` and
+///   `foo(a, b)` is `foo(a,b)`).
+///
 /// # Example
 /// ```
 /// use place_macro;
@@ -298,6 +509,19 @@ pub use place_macro_proc::identifier;
 /// let a = place_macro::stringify!("hello" + , ", " {(agent)} ' ' 0x2F);
 /// let b = stringify!("hello" + , ", " {(agent)} ' ' 0x2F);
 /// assert_eq!(a, b);
+///
+/// let a = place_macro::stringify!("compact", a + b * foo(c, 5) as u8);
+/// assert_eq!(a, "a+b*foo(c,5)as u8");
+///
+/// let a = place_macro::stringify!("compact", let x: &'a [u8] = { "hi" };);
+/// assert_eq!(a, "let x:&'a[u8]={\"hi\"};");
+///
+/// let a = place_macro::stringify!("spaced", a + b);
+/// assert_eq!(a, stringify!(a + b));
+///
+/// // the flag must be followed by comma
+/// let a = place_macro::stringify!("compact");
+/// assert_eq!(a, stringify!("compact"));
 /// ```
 pub use place_macro_proc::stringify;
 
@@ -327,52 +551,2767 @@ pub use place_macro_proc::replace_newline;
 pub use place_macro_proc::str_replace;
 
 /// Converts the given identifier to the given case. Second argument is the
-/// identifier and the first is string literal representing the target case.
+/// identifier (or string literal) and the first is string literal
+/// representing the target case.
 /// The target case can be one of:
-/// - `"TOCASE"`
-/// - `"tocase"`
-/// - `"toCase"`
-/// - `"ToCase"`
-/// - `"to_case"`
-/// - `"TO_CASE"`
+/// - `"TOCASE"`: upper flat case
+/// - `"tocase"`: flat case
+/// - `"toCase"`: camel case
+/// - `"ToCase"`: pascal case
+/// - `"to_case"`: snake case
+/// - `"TO_CASE"`: screaming snake case
+/// - `"tOcAsE"`: alternating case
+/// - `"to-case"`: kebab case
+/// - `"To-Case"`: train case
+///
+/// Kebab and train case don't produce valid identifiers, so the result is
+/// string literal.
 ///
 /// # Examples
 /// ```
-/// use place_macro::to_case;
+/// use place_macro::{place, to_case};
 ///
 /// let my_var = 5;
 /// let MyVar = 10;
 /// let n = to_case!("ToCase", my_var);
 /// assert_eq!(n, MyVar);
+///
+/// assert_eq!(to_case!("to-case", MyLongName), "my-long-name");
+/// assert_eq!(to_case!("To-Case", my_long_name), "My-Long-Name");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::to_case;
+///
+/// let MyVar = 10;
+/// let n = to_case!("PascalCase", my_var);
 /// ```
 pub use place_macro_proc::to_case;
 
-/// Evaluates the macros in this crate in reverse order
+/// Expands to the contents of the second group where every token (including
+/// the tokens in nested groups) has the span of the first token in the first
+/// group. This way errors in the generated code will point to the donor token
+/// and identifiers will resolve as if they were written where the donor was.
+///
+/// # Examples
+/// ```
+/// use place_macro::with_span_of;
+///
+/// macro_rules! define_x {
+///     ($name:ident) => {
+///         with_span_of!(($name), (let [x] = [5];));
+///     };
+/// }
+///
+/// // `x` is visible here because it has the span of `here`
+/// define_x!(here);
+/// assert_eq!(x, 5);
+/// ```
+pub use place_macro_proc::with_span_of;
+
+/// Expands to the given identifier. If the input is not a single identifier
+/// this fails with a compile error pointing at the input.
+///
+/// # Examples
+/// ```
+/// use place_macro::assert_ident;
+///
+/// let my_var = 5;
+/// assert_eq!(assert_ident!(my_var), 5);
+/// ```
+///
+/// Anything other than a single identifier fails:
+/// ```compile_fail
+/// use place_macro::assert_ident;
+///
+/// let n = assert_ident!(5);
+/// ```
+pub use place_macro_proc::assert_ident;
+
+/// Expands to the given literal. If the input is not a single literal this
+/// fails with a compile error pointing at the input.
+///
+/// # Examples
+/// ```
+/// use place_macro::assert_literal;
+///
+/// assert_eq!(assert_literal!("hello"), "hello");
+/// ```
+///
+/// Anything other than a single literal fails:
+/// ```compile_fail
+/// use place_macro::assert_literal;
+///
+/// let my_var = 5;
+/// let n = assert_literal!(my_var);
+/// ```
+pub use place_macro_proc::assert_literal;
+
+/// Creates identifier from string literal. Characters that cannot be in
+/// identifier are replaced with `_` and if the first character cannot start
+/// an identifier (e.g. it is a digit), the identifier is prefixed with `_`.
+/// Unlike `identifier` this will never panic on invalid input, it fails only
+/// when the string is empty.
+///
+/// # Examples
+/// ```
+/// use place_macro::escape_ident;
+///
+/// let foo_bar_baz = 5;
+/// assert_eq!(escape_ident!("foo-bar.baz"), 5);
+///
+/// let _1st_place = 1;
+/// assert_eq!(escape_ident!("1st place"), 1);
+/// ```
+///
+/// Empty string cannot be converted to identifier:
+/// ```compile_fail
+/// use place_macro::escape_ident;
+///
+/// let escape_ident!("") = 5;
+/// ```
+pub use place_macro_proc::escape_ident;
+
+/// Expands to the first identifier in the input, the identifier may also be
+/// inside a group. If there is no identifier, expands to nothing.
+///
+/// # Examples
+/// ```
+/// use place_macro::first_ident;
+///
+/// let foo = 5;
+/// let n = first_ident!(& ((* foo) bar));
+/// assert_eq!(n, 5);
+///
+/// // expands to nothing
+/// first_ident!(1 + (2 * [3]));
+/// ```
+pub use place_macro_proc::first_ident;
+
+/// Expands to all the identifiers in the input (also those inside groups) in
+/// the order in which they appear. Keywords and `_` are also included.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// macro_rules! names {
+///     ($($i:tt)*) => { [$(stringify!($i)),*] };
+/// }
+///
+/// let n = place!(names!(__collect_idents__(
+///     struct Foo { a: u8, b: (u16, [_; 5]) }
+/// )));
+/// assert_eq!(n, ["struct", "Foo", "a", "u8", "b", "u16", "_"]);
+///
+/// let n: [&str; 0] = place!(names!(__collect_idents__(5 + (3, 'a'))));
+/// assert!(n.is_empty());
+/// ```
+pub use place_macro_proc::collect_idents;
+
+/// Expands to nothing, same as `ignore`, but the input must be single string
+/// literal. This is meant to be used for comments in the `place` macro and it
+/// will not accidentaly ignore any code.
 ///
-/// to minimize conflicts, the macros are refered to as `__macro__` where
-/// macro is the name of the macro. Special case is the macro `dollar` that
-/// doesn't have any arguments.
 /// # Examples
 /// ```
 /// use place_macro::place;
 ///
+/// let n = place! {
+///     __comment__("this is a comment")
+///     5
+/// };
+/// assert_eq!(n, 5);
+/// ```
+///
+/// Anything else than string literal is error:
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// let mut n = 5;
 /// place! {
-///     pub fn __identifier__(my "_function")() -> bool {
-///         true
+///     __comment__(n = 10;)
+/// };
+/// ```
+pub use place_macro_proc::comment;
+
+/// Always fails with compile error that shows the input. This is meant only
+/// for debugging: inside the `place` macro the inner macros are expanded
+/// first, so you can see what they expand to.
+///
+/// # Examples
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// // fails with the message `fn my_fn () {}`
+/// place! {
+///     __debug__(fn __identifier__(my _fn)() {})
+/// }
+/// ```
+pub use place_macro_proc::debug;
+
+/// Shows the given string literal as warning, the compilation doesn't fail.
+///
+/// Proc macros cannot emit warnings on stable rust, so this expands to const
+/// item that uses deprecated struct with the message. The warning will be
+/// shown as `use of deprecated unit struct ... Note: <message>`. Because it
+/// expands to item, it can be used only where items are allowed.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// place! {
+///     __note__(__string__("function " foo " is generated"))
+///     fn foo() {}
+/// }
+/// foo();
+/// ```
+///
+/// The warning is really emitted:
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// use place_macro::note;
+///
+/// note!("this is a warning");
+/// ```
+pub use place_macro_proc::note;
+
+/// Fails with compile error with the given message. Unlike the std macro
+/// `compile_error`, the message may be created by other macros in the `place`
+/// macro.
+///
+/// # Examples
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// // fails with message `Invalid value 5.`
+/// place! {
+///     __compile_error__(__string__("Invalid value " 5 "."))
+/// }
+/// ```
+///
+/// The errors of this crate use the fully qualified `::core::compile_error!`,
+/// so importing this macro (e.g. with glob import) doesn't change them:
+/// ```compile_fail
+/// use place_macro::*;
+///
+/// // fails with message `'1a' is not valid identifier`
+/// let place!(__identifier__(1 a)) = 5;
+/// ```
+/// ```compile_fail
+/// use place_macro::*;
+///
+/// // fails with message `x`
+/// place!(__compile_error__("x"));
+/// ```
+pub use place_macro_proc::compile_error;
+
+/// Invokes macro. The first argument is the name of the macro, it is created
+/// in the same way as in `identifier`. The second argument is group with the
+/// arguments to the macro, its brackets are used for the invocation.
+///
+/// # Examples
+/// ```
+/// use place_macro::{apply, place};
+///
+/// let v = apply!(ve c, [1, 2, 3]);
+/// assert_eq!(v, [1, 2, 3]);
+///
+/// place! {
+///     macro_rules! __identifier__(add _ one) {
+///         (__dollar__ e:expr) => { __dollar__ e + 1 };
 ///     }
+///     let n = __apply__(add _ one, (5));
 /// }
-/// assert!(my_function());
+/// assert_eq!(n, 6);
+/// ```
+///
+/// It is error if the name is not valid identifier:
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(__apply__(1 2, ()));
+/// ```
+/// ```compile_fail
+/// use place_macro::apply;
+///
+/// apply!(my_mac "-x", ());
+/// ```
+pub use place_macro_proc::apply;
+
+/// Expands to string literal with the kind of the given literal. The kind may
+/// be one of: `"int"`, `"float"`, `"string"`, `"char"`, `"byte"`,
+/// `"bytestring"` or `"bool"`.
+///
+/// # Examples
+/// ```
+/// use place_macro::literal_kind;
+///
+/// assert_eq!(literal_kind!(5), "int");
+/// assert_eq!(literal_kind!(0x5u8), "int");
+/// assert_eq!(literal_kind!(5.0), "float");
+/// assert_eq!(literal_kind!(1e3f32), "float");
+/// assert_eq!(literal_kind!("hello"), "string");
+/// assert_eq!(literal_kind!(r"hello"), "string");
+/// assert_eq!(literal_kind!('h'), "char");
+/// assert_eq!(literal_kind!(b'h'), "byte");
+/// assert_eq!(literal_kind!(b"hello"), "bytestring");
+/// assert_eq!(literal_kind!(true), "bool");
+/// ```
+///
+/// Other tokens are error:
+/// ```compile_fail
+/// use place_macro::literal_kind;
+///
+/// let s = literal_kind!(hello);
+/// ```
+pub use place_macro_proc::literal_kind;
+
+/// Expands to comma separated string literals, one for each token. Groups are
+/// single token, so they are stringified together with their brackets.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// let s = place!([__quote_each__(a + (b c) 5 "s")]);
+/// assert_eq!(s, ["a", "+", "(b c)", "5", "\"s\""]);
+/// ```
+pub use place_macro_proc::quote_each;
+
+/// Expands to the first segment of path (e.g. `std` for `std::vec::Vec`).
+/// Generic arguments are ignored.
+///
+/// # Examples
+/// ```
+/// use place_macro::path_first;
+///
+/// let std = 5;
+/// assert_eq!(path_first!(std::collections::HashMap<K, V>), 5);
+/// assert_eq!(path_first!(::std::collections::HashMap), 5);
+/// assert_eq!(path_first!(std), 5);
+/// ```
+pub use place_macro_proc::path_first;
+
+/// Expands to the last segment of path (e.g. `Vec` for `std::vec::Vec`).
+/// Generic arguments are ignored.
+///
+/// # Examples
+/// ```
+/// use place_macro::path_last;
+///
+/// let HashMap = 5;
+/// assert_eq!(path_last!(std::collections::HashMap), 5);
+/// assert_eq!(path_last!(::std::collections::HashMap<K, V>), 5);
+/// assert_eq!(path_last!(std::collections::HashMap::<K, V>), 5);
+/// assert_eq!(path_last!(HashMap), 5);
+/// ```
+pub use place_macro_proc::path_last;
+
+/// Joins the segments with `::` to create path. The segments may be separated
+/// with commas or just with spaces.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
 ///
 /// place! {
-///     macro_rules! mac {
-///         (__dollar__ var: literal) => {
-///             __dollar__ var
-///         }
+///     use __join_path__(std, collections __ident__(Hash Map));
+/// }
+/// let m = HashMap::<u8, u8>::new();
+/// assert!(m.is_empty());
+///
+/// let v: place!(__join_path__(std vec Vec)<u8>) = vec![1];
+/// assert_eq!(v, [1]);
+/// ```
+pub use place_macro_proc::join_path;
+
+/// Chooses between two groups based on the environment variable
+/// `CARGO_FEATURE_<NAME>` (`<NAME>` is the feature name in uppercase with `-`
+/// replaced with `_`). If the variable is set, expands to the contents of the
+/// first group, otherwise expands to the contents of the second group.
+///
+/// **Cargo doesn't set these variables when compiling your crate, only when
+/// running build scripts.** Without build script that passes the variable to
+/// the compiler, this macro always expands to the second group:
+/// ```ignore
+/// // build.rs
+/// fn main() {
+///     if std::env::var_os("CARGO_FEATURE_MY_FEATURE").is_some() {
+///         println!("cargo:rustc-env=CARGO_FEATURE_MY_FEATURE=1");
 ///     }
 /// }
-/// assert_eq!("hi", mac!("hi"));
+/// ```
 ///
-/// let res = place!(__string__(1 __string__(2 __identity__(3 __string__(4)))));
-/// assert_eq!(res, "123__string__4");
+/// With the build script, the macro reflects the features of the crate that
+/// is being compiled (not of `place_macro`), and changing the features reruns
+/// the build script and recompiles the crate. The compiler doesn't know that
+/// the macro reads the variable, so setting the variable in other way will
+/// not recompile code that was already compiled.
+///
+/// This macro is available only with the feature `std`.
+///
+/// # Examples
+/// ```
+/// use place_macro::if_feature;
+///
+/// // build script of this crate doesn't set the variable
+/// let s = if_feature!("my-feature", ("enabled"), ("disabled"));
+/// assert_eq!(s, "disabled");
+/// ```
+#[cfg(feature = "std")]
+pub use place_macro_proc::if_feature;
+
+/// Converts the input to string literal with spacing similar to rustfmt.
+/// This is useful for generating human readable messages and documentation.
+///
+/// The spacing is only heuristic (the input is not parsed), the rules are:
+/// - binary operators have space on both sides
+/// - unary operators, `.`, `::`, `?` and `!` of macro invocation have no
+///   spaces around them
+/// - `,`, `;` and `:` are followed by space
+/// - there is no space inside parenthesis and brackets and there is no space
+///   before them when they follow identifier (call or indexing)
+/// - braces have space inside them and before them
+/// - `<` and `>` of generics have no spaces around them
+/// - closure parameters have no space inside the `|`
+///
+/// # Examples
+/// ```
+/// use place_macro::stringify_pretty;
+///
+/// assert_eq!(stringify_pretty!(a+b*(c-d)), "a + b * (c - d)");
+/// assert_eq!(stringify_pretty!(!a && -b<=c[0]), "!a && -b <= c[0]");
+/// assert_eq!(
+///     stringify_pretty!(v.iter().map(|x| x*2).collect::<Vec<_>>()?),
+///     "v.iter().map(|x| x * 2).collect::<Vec<_>>()?"
+/// );
+/// assert_eq!(
+///     stringify_pretty!(fn foo<T: Display>(x: &T, y: Vec<Vec<u8>>) -> Result<(), String> {}),
+///     "fn foo<T: Display>(x: &T, y: Vec<Vec<u8>>) -> Result<(), String> {}"
+/// );
+/// assert_eq!(
+///     stringify_pretty!(&'a mut HashMap<K, V>),
+///     "&'a mut HashMap<K, V>"
+/// );
+/// assert_eq!(
+///     stringify_pretty!(#[derive(Debug)] pub struct A { x: [u8; 5] }),
+///     "#[derive(Debug)] pub struct A { x: [u8; 5] }"
+/// );
+/// assert_eq!(
+///     stringify_pretty!(if a { vec![1, 2] } else { 0..5 }),
+///     "if a { vec![1, 2] } else { 0..5 }"
+/// );
+/// ```
+pub use place_macro_proc::stringify_pretty;
+
+/// Removes duplicate lines from string literal. The first occurrence of each
+/// line is kept and the order of the lines is preserved. Blank lines are
+/// also deduplicated.
+///
+/// The lines are joined with `\n`, trailing newline is preserved.
+///
+/// # Examples
+/// ```
+/// use place_macro::unique_lines;
+///
+/// assert_eq!(
+///     unique_lines!("use a;\nuse b;\nuse a;\nuse c;\nuse b;"),
+///     "use a;\nuse b;\nuse c;"
+/// );
+/// assert_eq!(unique_lines!("b\n\na\n\nb\n"), "b\n\na\n");
+/// assert_eq!(unique_lines!("a\r\nb\r\na"), "a\nb");
+/// ```
+pub use place_macro_proc::unique_lines;
+
+/// Sorts lines in string literal lexicographically. If the string is preceded
+/// by `"rev"`, the lines are sorted in descending order.
+///
+/// The lines are joined with `\n`, trailing newline is preserved.
+///
+/// # Examples
+/// ```
+/// use place_macro::sort_lines;
+///
+/// assert_eq!(sort_lines!("use c;\nuse a;\nuse b;"), "use a;\nuse b;\nuse c;");
+/// assert_eq!(sort_lines!("rev", "b\nc\na\n"), "c\nb\na\n");
+/// assert_eq!(sort_lines!("b\r\na\n"), "a\nb\n");
+/// assert_eq!(sort_lines!("b\na",), "a\nb");
+/// assert_eq!(sort_lines!("rev", "a\nb",), "b\na");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::sort_lines;
+///
+/// let s = sort_lines!("desc", "b\na");
+/// ```
+pub use place_macro_proc::sort_lines;
+
+/// Expands to the names of generic parameters separated by comma. The input
+/// is the generic parameters (e.g. from declaration of struct), they may be
+/// enclosed in `<` and `>`. Bounds, defaults and attributes are removed,
+/// lifetimes keep their `'` and const parameters expand only to the name.
+///
+/// This is useful for creating the type in `impl` block.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, type_params};
+///
+/// assert_eq!(
+///     place!(__stringify__(__type_params__(<T: Clone + Into<Vec<u8>>, U = u8>))),
+///     "T, U"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__type_params__('a, 'b: 'a, F: Fn(u8) -> u8))),
+///     "'a, 'b, F"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__type_params__(F: Fn() -> Option<u8>, G))),
+///     "F, G"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__type_params__(<#[cfg(all())] T, const N: usize = 3,>))),
+///     "T, N"
+/// );
+///
+/// struct Arr<T: Copy + Default, const N: usize>([T; N]);
+///
+/// macro_rules! impl_new {
+///     ($name:ident [$($g:tt)*]) => {
+///         place! {
+///             impl<$($g)*> $name<__type_params__($($g)*)> {
+///                 fn new() -> Self {
+///                     Self([T::default(); N])
+///                 }
+///             }
+///         }
+///     };
+/// }
+/// impl_new!(Arr[T: Copy + Default, const N: usize]);
+///
+/// let a = Arr::<u8, 3>::new();
+/// assert_eq!(a.0, [0, 0, 0]);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// struct A<T, U>(T, U);
+///
+/// place! {
+///     type B<T, U> = A<__type_params__(<T, , U>)>;
+/// }
+/// ```
+pub use place_macro_proc::type_params;
+
+/// Removes bounds and defaults from generic parameters. The input is the
+/// generic parameters (e.g. from declaration of struct), they may be enclosed
+/// in `<` and `>` and if they are, the result is also enclosed in them.
+/// Attributes are kept and const parameters keep their type.
+///
+/// This is useful when the generic parameters are used at place where bounds
+/// or defaults are not allowed (e.g. defaults in `impl` block).
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, strip_bounds};
+///
+/// assert_eq!(
+///     place!(__stringify__(__strip_bounds__(<T: Clone + Into<Vec<u8>>, U = u8>))),
+///     "< T, U >"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__strip_bounds__('a, 'b: 'a, F: Fn(u8) -> u8,))),
+///     "'a, 'b, F"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__strip_bounds__(<T, const N: usize = 3>))),
+///     "< T, const N : usize >"
+/// );
+///
+/// struct Wrap<T: Clone = u8>(T);
+///
+/// macro_rules! impl_get {
+///     ($name:ident [$($g:tt)*]) => {
+///         place! {
+///             impl __strip_bounds__(<$($g)*>) $name<__type_params__($($g)*)>
+///             where
+///                 T: Clone,
+///             {
+///                 fn get(&self) -> T {
+///                     self.0.clone()
+///                 }
+///             }
+///         }
+///     };
+/// }
+/// impl_get!(Wrap[T: Clone = u8]);
+///
+/// assert_eq!(Wrap(5).get(), 5);
+/// ```
+pub use place_macro_proc::strip_bounds;
+
+/// Converts identifier or string literal to screaming snake case identifier.
+/// Same as `to_case!("TO_CASE", ...)`.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, screaming};
+///
+/// const MY_CONST: u8 = 5;
+/// assert_eq!(screaming!(my_const), 5);
+/// assert_eq!(screaming!("myConst"), 5);
+/// assert_eq!(place!(__stringify__(__screaming__(MyConst))), "MY_CONST");
+/// ```
+pub use place_macro_proc::screaming;
+
+/// Converts identifier or string literal to pascal case identifier.
+/// Same as `to_case!("ToCase", ...)`.
+///
+/// # Examples
+/// ```
+/// #![allow(non_snake_case)]
+/// use place_macro::{place, pascal};
+///
+/// struct MyStruct;
+/// let _: MyStruct = pascal!(my_struct);
+/// let _: MyStruct = pascal!("my struct");
+/// assert_eq!(place!(__stringify__(__pascal__(MY_STRUCT))), "MyStruct");
+/// ```
+pub use place_macro_proc::pascal;
+
+/// Converts identifier or string literal to camel case identifier.
+/// Same as `to_case!("toCase", ...)`.
+///
+/// # Examples
+/// ```
+/// #![allow(non_snake_case)]
+/// use place_macro::{place, camel};
+///
+/// let myVar = 5;
+/// assert_eq!(camel!(my_var), 5);
+/// assert_eq!(camel!("MyVar"), 5);
+/// assert_eq!(place!(__stringify__(__camel__(MY_VAR))), "myVar");
+/// ```
+pub use place_macro_proc::camel;
+
+/// Converts identifier or string literal to snake case identifier.
+/// Same as `to_case!("to_case", ...)`.
+///
+/// # Examples
+/// ```
+/// #![allow(non_snake_case)]
+/// use place_macro::{place, snake};
+///
+/// let my_var = 5;
+/// assert_eq!(snake!(MyVar), 5);
+/// assert_eq!(snake!("my-var"), 5);
+/// assert_eq!(place!(__stringify__(__snake__(myVar))), "my_var");
+/// ```
+pub use place_macro_proc::snake;
+
+/// Converts identifier or string literal to kebab case string literal (kebab
+/// case is not valid identifier). Same as `to_case!("to-case", ...)`.
+///
+/// # Examples
+/// ```
+/// use place_macro::{kebab, place};
+///
+/// assert_eq!(kebab!(MyCrate), "my-crate");
+/// assert_eq!(kebab!("my_crate"), "my-crate");
+/// assert_eq!(place!(__kebab__(myCrate)), "my-crate");
+/// ```
+pub use place_macro_proc::kebab;
+
+/// Expands to string literal with the ordinal number (e.g. `"1st"`) of the
+/// given integer literal.
+///
+/// # Examples
+/// ```
+/// use place_macro::ordinal;
+///
+/// assert_eq!(ordinal!(0), "0th");
+/// assert_eq!(ordinal!(1), "1st");
+/// assert_eq!(ordinal!(2), "2nd");
+/// assert_eq!(ordinal!(3), "3rd");
+/// assert_eq!(ordinal!(4), "4th");
+/// assert_eq!(ordinal!(11), "11th");
+/// assert_eq!(ordinal!(12), "12th");
+/// assert_eq!(ordinal!(13), "13th");
+/// assert_eq!(ordinal!(21), "21st");
+/// assert_eq!(ordinal!(112), "112th");
+/// assert_eq!(ordinal!(0x7b), "123rd");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::ordinal;
+///
+/// let s = ordinal!(-1);
+/// ```
+pub use place_macro_proc::ordinal;
+
+/// Expands to naive english plural of the given identifier or string literal
+/// (`box` -> `boxes`, `city` -> `cities`, `dog` -> `dogs`). Identifier
+/// expands to identifier and string expands to string.
+///
+/// The plural is created only using these rules:
+/// - words ending with consonant and `y` replace the `y` with `ies`
+/// - words ending with `s`, `x`, `z`, `ch` or `sh` append `es`
+/// - other words append `s`
+///
+/// Irregular plurals (e.g. `child` -> `children`) can be given explicitly as
+/// the second argument.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, plural};
+///
+/// assert_eq!(plural!("dog"), "dogs");
+/// assert_eq!(plural!("day"), "days");
+/// assert_eq!(plural!("city"), "cities");
+/// assert_eq!(plural!("box"), "boxes");
+/// assert_eq!(plural!("bus"), "buses");
+/// assert_eq!(plural!("match"), "matches");
+/// assert_eq!(plural!("dish"), "dishes");
+/// assert_eq!(plural!("child", "children"), "children");
+///
+/// assert_eq!(place!(__stringify__(__plural__(Entry))), "Entries");
+/// assert_eq!(place!(__stringify__(__plural__(MAX_INDEX))), "MAX_INDEXES");
+/// assert_eq!(place!(__stringify__(__plural__(person, people))), "people");
+/// ```
+pub use place_macro_proc::plural;
+
+/// Expands to naive english singular of the given identifier or string
+/// literal (`boxes` -> `box`, `cities` -> `city`, `dogs` -> `dog`). It is the
+/// inverse of `plural`. Identifier expands to identifier and string expands
+/// to string.
+///
+/// The singular is created only using these rules:
+/// - `ies` is replaced with `y`
+/// - `es` is removed after `ss`, `x`, `z`, `ch` and `sh`
+/// - `s` is removed, unless the word ends with `ss`, `us` or `is`
+/// - other words are unchanged
+///
+/// Irregular plurals are not handled (e.g. `children`, `people`, `mice`,
+/// `knives`, `heroes` or `buses`), the singular can be given explicitly as
+/// the second argument.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, singular};
+///
+/// assert_eq!(singular!("dogs"), "dog");
+/// assert_eq!(singular!("days"), "day");
+/// assert_eq!(singular!("cities"), "city");
+/// assert_eq!(singular!("boxes"), "box");
+/// assert_eq!(singular!("classes"), "class");
+/// assert_eq!(singular!("matches"), "match");
+/// assert_eq!(singular!("cases"), "case");
+/// assert_eq!(singular!("status"), "status");
+/// assert_eq!(singular!("children", "child"), "child");
+///
+/// assert_eq!(place!(__stringify__(__singular__(Entries))), "Entry");
+/// assert_eq!(place!(__stringify__(__singular__(ITEMS))), "ITEM");
+/// assert_eq!(place!(__stringify__(__singular__(people, person))), "person");
+/// ```
+pub use place_macro_proc::singular;
+
+/// Creates raw identifier (e.g. `r#type`) in the same way as `identifier`.
+/// This can be used to create identifiers from keywords (except `crate`,
+/// `self`, `Self` and `super` which cannot be raw identifiers).
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, raw_identifier};
+///
+/// let r#type = 5;
+/// assert_eq!(raw_identifier!(ty pe), 5);
+/// assert_eq!(place!(__stringify__(__raw_ident__(st ruct))), "r#struct");
+///
+/// struct A { r#fn: u8 }
+/// let a = A { r#fn: 1 };
+/// assert_eq!(place!(a.__raw_identifier__(f n)), 1);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::raw_identifier;
+///
+/// let n = raw_identifier!(sel f);
+/// ```
+pub use place_macro_proc::raw_identifier;
+
+/// Creates byte literal (e.g. `b'a'`) from integer literal in range
+/// `0..=255`, ASCII char literal or string literal with single ASCII
+/// character.
+///
+/// The result is byte literal, not `u8` suffixed integer, so it is also
+/// stringified as byte literal.
+///
+/// # Examples
+/// ```
+/// use place_macro::{byte, place};
+///
+/// assert_eq!(byte!(97), b'a');
+/// assert_eq!(byte!(0xff), b'\xff');
+/// assert_eq!(byte!('a'), b'a');
+/// assert_eq!(byte!("\n"), b'\n');
+/// assert_eq!(place!(__stringify__(__byte__(65))), "b'A'");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::byte;
+///
+/// let b = byte!(256);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::byte;
+///
+/// let b = byte!("ab");
+/// ```
+pub use place_macro_proc::byte;
+
+/// Transliterates string literal to ASCII. Accents are removed from common
+/// latin characters (e.g. `é` -> `e`, `ß` -> `ss`) and other non-ASCII
+/// characters are removed. The transliteration is only best-effort.
+///
+/// This is useful when creating identifiers from user-facing names.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, to_ascii};
+///
+/// assert_eq!(to_ascii!("café"), "cafe");
+/// assert_eq!(to_ascii!("Příliš žluťoučký kůň"), "Prilis zlutoucky kun");
+/// assert_eq!(to_ascii!("Æsir Straße Øresund"), "AEsir Strasse Oresund");
+/// assert_eq!(to_ascii!("日本 rust"), " rust");
+///
+/// let cafe_menu = 5;
+/// assert_eq!(place!(__identifier__(__to_ascii__("café") _menu)), 5);
+/// ```
+pub use place_macro_proc::to_ascii;
+
+/// Expands to the numeric value of char, byte or integer literal as decimal
+/// integer literal without suffix.
+///
+/// # Examples
+/// ```
+/// use place_macro::{literal_value, place};
+///
+/// assert_eq!(literal_value!('A'), 65);
+/// assert_eq!(literal_value!('\u{1f980}'), 0x1f980);
+/// assert_eq!(literal_value!(b'\n'), 10);
+/// assert_eq!(literal_value!(0x2a), 42);
+/// assert_eq!(literal_value!(0o17u8), 15);
+/// assert_eq!(literal_value!(0b1010), 10);
+/// assert_eq!(literal_value!(1_000), 1000);
+/// assert_eq!(place!(__stringify__(__literal_value__(0xffu8))), "255");
+/// ```
+///
+/// String literals are not supported:
+/// ```compile_fail
+/// use place_macro::literal_value;
+///
+/// let n = literal_value!("A");
+/// ```
+pub use place_macro_proc::literal_value;
+
+/// Expands to the first integer literal clamped to the range given by the
+/// second and third integer literal (inclusive). The integers may be
+/// negative.
+///
+/// # Examples
+/// ```
+/// use place_macro::{clamp, place};
+///
+/// assert_eq!(clamp!(-5, 0, 10), 0);
+/// assert_eq!(clamp!(5, 0, 10), 5);
+/// assert_eq!(clamp!(15, 0, 10), 10);
+/// assert_eq!(clamp!(0x100, -10, 0xff), 255);
+/// assert_eq!(place!(__clamp__(-5, -3, 3)), -3);
+///
+/// macro_rules! buffer {
+///     ($n:literal) => {
+///         [0_u8; clamp!($n, 16, 4096)]
+///     };
+/// }
+/// assert_eq!(buffer!(1).len(), 16);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::clamp;
+///
+/// let n = clamp!(5, 10, 0);
+/// ```
+pub use place_macro_proc::clamp;
+
+/// Doubles all `{` and `}` in string literal, so that it can be used as
+/// format string without the braces being placeholders. Braces that are
+/// already doubled are doubled again.
+///
+/// # Examples
+/// ```
+/// use place_macro::{escape_braces, place};
+///
+/// assert_eq!(escape_braces!("fn a() {}"), "fn a() {{}}");
+/// assert_eq!(escape_braces!("{{x}}"), "{{{{x}}}}");
+///
+/// let s = place!(format!(__str__(__escape_braces__("{a}") " = {}"), 5));
+/// assert_eq!(s, "{a} = 5");
+/// ```
+pub use place_macro_proc::escape_braces;
+
+/// Expands to the contents of the group at the given index. The first
+/// argument is the index (starting at 0) and the other arguments are the
+/// groups to choose from.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, select_index};
+///
+/// assert_eq!(select_index!(1, (1 + 1), [2 + 2], {3 + 3}), 4);
+///
+/// // the index may be result of other macro
+/// assert_eq!(place!(__select_index__(__clamp__(7, 0, 2), (1), (2), (3))), 3);
+/// assert_eq!(place!(__select_index__(__literal_value__(b'\x01'), (a), ("b"))), "b");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::select_index;
+///
+/// let n = select_index!(3, (1), (2), (3));
+/// ```
+pub use place_macro_proc::select_index;
+
+/// Repeats the tokens given number of times with the separator between the
+/// copies (not after the last). The first argument is the count, the second
+/// is group with the separator and the rest are the tokens.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, repeat_sep};
+///
+/// assert_eq!(place!(__stringify__(__repeat_sep__(3, (,), x))), "x, x, x");
+/// assert_eq!(place!(__stringify__(__repeat_sep__(1, (,), x))), "x");
+/// assert_eq!(place!(__stringify__(__repeat_sep__(0, (,), x))), "");
+///
+/// // the tokens may contain commas
+/// assert_eq!(place!(__stringify__(__repeat_sep__(2, (;), a, b))), "a, b; a, b");
+///
+/// assert_eq!(repeat_sep!(4, (*), 2), 16);
+/// ```
+pub use place_macro_proc::repeat_sep;
+
+/// Applies operation to each of the string literals. The first argument is
+/// the operation and the rest are the string literals. The result is the
+/// changed string literals separated by commas.
+///
+/// The operations are:
+/// - `"upper"`: converts to uppercase
+/// - `"lower"`: converts to lowercase
+/// - `"trim"`: removes whitespace from the start and end
+/// - `"capitalize"`: converts the first character to uppercase
+///
+/// # Examples
+/// ```
+/// use place_macro::{map_str, place};
+///
+/// assert_eq!(place!([__map_str__("upper", "a", "b", "c")]), ["A", "B", "C"]);
+/// assert_eq!(place!([__map_str__("lower", "Hello", "WORLD",)]), ["hello", "world"]);
+/// assert_eq!(place!([__map_str__("trim", " a ", "\tb\n")]), ["a", "b"]);
+/// assert_eq!(
+///     place!([__map_str__("capitalize", "hello", "ëlement", "")]),
+///     ["Hello", "Ëlement", ""]
+/// );
+/// assert_eq!(map_str!("upper", "single"), "SINGLE");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::map_str;
+///
+/// let a = map_str!("reverse", "ab");
+/// ```
+pub use place_macro_proc::map_str;
+
+/// Evaluates integer expression in string literal and expands to the
+/// resulting integer literal. The expression may contain decimal integers,
+/// parenthesis and the operators `+`, `-`, `*`, `/` and `%` with the same
+/// precedence as in rust. The computation is done with `i128` and overflow
+/// or division by zero is error.
+///
+/// # Examples
+/// ```
+/// use place_macro::{const_eval, place};
+///
+/// assert_eq!(const_eval!("2 * (3 + 4)"), 14);
+/// assert_eq!(const_eval!("2 * 3 + 4"), 10);
+/// assert_eq!(const_eval!("2 + 3 * 4"), 14);
+/// assert_eq!(const_eval!("10 - 4 - 3"), 3);
+/// assert_eq!(const_eval!("-7 / 2"), -3);
+/// assert_eq!(const_eval!("-7 % 3"), -1);
+/// assert_eq!(const_eval!("-(1_000 - -2)"), -1002);
+///
+/// // the expression may be created by other macros
+/// macro_rules! size {
+///     ($n:literal) => {
+///         place!(__const_eval__(__string__(2 "*" $n "+" 1)))
+///     };
+/// }
+/// assert_eq!(size!(5), 11);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::const_eval;
+///
+/// let n = const_eval!("1 / (2 - 2)");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::const_eval;
+///
+/// let n = const_eval!("170141183460469231731687303715884105727 + 1");
+/// ```
+pub use place_macro_proc::const_eval;
+
+/// Pads the tokens with filler to the given number of tokens. The first
+/// argument is the number of tokens, the second is group with the filler and
+/// the rest are the tokens. Only the top level tokens are counted (group is
+/// single token).
+///
+/// If the filler has more tokens, its tokens are repeated one by one, so the
+/// result always has exactly the given number of tokens. If there are more
+/// tokens than the given number, the tokens at the end are removed.
+///
+/// # Examples
+/// ```
+/// use place_macro::{pad_tokens, place};
+///
+/// assert_eq!(place!(__stringify__(__pad_tokens__(5, (0), 1 2))), "1 2 0 0 0");
+/// assert_eq!(place!(__stringify__(__pad_tokens__(2, (0), 1 2))), "1 2");
+/// assert_eq!(place!(__stringify__(__pad_tokens__(2, (0), 1 2 3 4))), "1 2");
+/// assert_eq!(place!(__stringify__(__pad_tokens__(4, (a b), (1 2)))), "(1 2) a b a");
+///
+/// // commas are also tokens
+/// macro_rules! triple {
+///     ($($e:literal),*) => {
+///         place!((__pad_tokens__(6, (0,), $($e,)*)))
+///     };
+/// }
+/// assert_eq!(triple!(1), (1, 0, 0));
+/// assert_eq!(triple!(1, 2, 3, 4), (1, 2, 3));
+/// ```
+pub use place_macro_proc::pad_tokens;
+
+/// Expands to the contents of the first group if it is not empty, otherwise
+/// expands to the contents of the second group. Whitespace is not part of the
+/// tokens, so group with only whitespace is empty.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, with_default};
+///
+/// assert_eq!(with_default!((5), (10)), 5);
+/// assert_eq!(with_default!((), (10)), 10);
+/// assert_eq!(with_default!((   ), (10)), 10);
+/// assert_eq!(place!(__stringify__(__with_default__((a, (b)), (c)))), "a, (b)");
+///
+/// macro_rules! port {
+///     ($($p:literal)?) => {
+///         with_default!(($($p)?), (8080))
+///     };
+/// }
+/// assert_eq!(port!(), 8080);
+/// assert_eq!(port!(80), 80);
+///
+/// macro_rules! ty {
+///     ($($t:ty)?) => {
+///         place!(__with_default__(($($t)?), (u8)))
+///     };
+/// }
+/// let a: ty!() = 5_u8;
+/// let b: ty!(&str) = "hi";
+/// ```
+pub use place_macro_proc::with_default;
+
+/// Removes the marker identifier from the start and end of the tokens. The
+/// first argument is the marker and the rest are the tokens. Only one marker
+/// is removed at each end and only top level tokens are considered.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, trim_idents};
+///
+/// assert_eq!(place!(__stringify__(__trim_idents__(m, m a b m))), "a b");
+/// assert_eq!(place!(__stringify__(__trim_idents__(m, m a b))), "a b");
+/// assert_eq!(place!(__stringify__(__trim_idents__(m, a b m))), "a b");
+/// assert_eq!(place!(__stringify__(__trim_idents__(m, a m b))), "a m b");
+/// assert_eq!(place!(__stringify__(__trim_idents__(m, m m a m m))), "m a m");
+/// assert_eq!(place!(__stringify__(__trim_idents__(m, (m a m)))), "(m a m)");
+///
+/// assert_eq!(trim_idents!(sep, sep 1 + 2 sep), 3);
+/// ```
+pub use place_macro_proc::trim_idents;
+
+/// Expands to `true` if the identifier or string literal is strict or
+/// reserved keyword in any edition, otherwise expands to `false`.
+///
+/// # Examples
+/// ```
+/// use place_macro::{is_keyword, place};
+///
+/// assert!(is_keyword!(match));
+/// assert!(is_keyword!("yield"));
+/// assert!(is_keyword!("gen"));
+/// assert!(is_keyword!(dyn));
+/// assert!(is_keyword!(Self));
+/// assert!(!is_keyword!(foo));
+/// assert!(!is_keyword!(r#match));
+/// assert!(place!(__is_keyword__(__string__(ty pe))));
+/// ```
+pub use place_macro_proc::is_keyword;
+
+/// Rounds the first integer literal up to multiple of the second integer
+/// literal. The second literal (alignment) must be power of two.
+///
+/// # Examples
+/// ```
+/// use place_macro::{align_up, place};
+///
+/// assert_eq!(align_up!(13, 8), 16);
+/// assert_eq!(align_up!(16, 8), 16);
+/// assert_eq!(align_up!(0, 4096), 0);
+/// assert_eq!(align_up!(1, 1), 1);
+/// assert_eq!(place!(__align_up__(-13, 8)), -8);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::align_up;
+///
+/// let n = align_up!(13, 6);
+/// ```
+pub use place_macro_proc::align_up;
+
+/// Rounds the first integer literal down to multiple of the second integer
+/// literal. The second literal (alignment) must be power of two.
+///
+/// # Examples
+/// ```
+/// use place_macro::{align_down, place};
+///
+/// assert_eq!(align_down!(13, 8), 8);
+/// assert_eq!(align_down!(16, 8), 16);
+/// assert_eq!(align_down!(4095, 4096), 0);
+/// assert_eq!(place!(__align_down__(-13, 8)), -16);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::align_down;
+///
+/// let n = align_down!(13, 0);
+/// ```
+pub use place_macro_proc::align_down;
+
+/// Expands to the minimum number of bits needed to represent the integer
+/// literal. Zero needs `0` bits. Negative values are error.
+///
+/// # Examples
+/// ```
+/// use place_macro::{bit_width, place};
+///
+/// assert_eq!(bit_width!(0), 0);
+/// assert_eq!(bit_width!(1), 1);
+/// assert_eq!(bit_width!(2), 2);
+/// assert_eq!(bit_width!(255), 8);
+/// assert_eq!(bit_width!(256), 9);
+/// assert_eq!(bit_width!(0xffff_ffff_u32), 32);
+/// assert_eq!(place!(__bit_width__(__const_eval__("1000 - 1"))), 10);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::bit_width;
+///
+/// let n = bit_width!(-1);
+/// ```
+pub use place_macro_proc::bit_width;
+
+/// Pairs each token with its index (starting at 0). Each pair is in
+/// parenthesis, e.g. `a b c` expands to `(0 a) (1 b) (2 c)`. Groups are
+/// single token.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(
+///     place!(__stringify__(__enumerate__(a (b c) d))),
+///     "(0 a) (1 (b c)) (2 d)"
+/// );
+///
+/// struct Rgb(u8, u8, u8);
+///
+/// macro_rules! accessors {
+///     ($t:ident: $(($i:tt $name:ident))*) => {
+///         impl $t {
+///             $(fn $name(&self) -> u8 {
+///                 self.$i
+///             })*
+///         }
+///     };
+/// }
+///
+/// place!(accessors!(Rgb: __enumerate__(r g b)));
+///
+/// let c = Rgb(1, 2, 3);
+/// assert_eq!((c.r(), c.g(), c.b()), (1, 2, 3));
+/// ```
+pub use place_macro_proc::enumerate;
+
+/// Removes all the groups (at any depth) and leaves only their contents.
+///
+/// The result will usually not be valid rust code, this is meant for
+/// assembling text (e.g. with `__string__` or `__stringify__`).
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(__flatten_deep__(((a (b)) c)))), "a b c");
+/// assert_eq!(place!(__stringify__(__flatten_deep__(a () [{}] b))), "a b");
+/// assert_eq!(place!(__string__(__flatten_deep__((x [_ {1}])))), "x_1");
+/// ```
+pub use place_macro_proc::flatten_deep;
+
+/// Removes the type suffix from numeric literal. The value is kept exactly
+/// as written.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(__unsuffix__(5u8))), "5");
+/// assert_eq!(place!(__stringify__(__unsuffix__(0x1F_usize))), "0x1F_");
+/// assert_eq!(place!(__stringify__(__unsuffix__(3.0f32))), "3.0");
+/// assert_eq!(place!(__stringify__(__unsuffix__(2f64))), "2.0");
+/// assert_eq!(place!(__stringify__(__unsuffix__(1.5))), "1.5");
+/// let a: f32 = place!(__unsuffix__(0.1f64));
+/// assert_eq!(a, 0.1);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// let a = place!(__unsuffix__("5u8"));
+/// ```
+pub use place_macro_proc::unsuffix;
+
+/// Expands to string literal with the value of the given string literal
+/// escaped as it would be in rust source (without the surrounding quotes).
+/// The result can be put between quotes in generated source code.
+///
+/// Inside `place` it is also available as `__escape_string__`.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, quote_str};
+///
+/// assert_eq!(quote_str!(r#"a"b"#), r#"a\"b"#);
+/// assert_eq!(quote_str!("a\\b\n"), r"a\\b\n");
+/// assert_eq!(place!(__escape_string__("plain")), "plain");
+/// assert_eq!(
+///     place!(__string__("let s = \"" __quote_str__("\"hi\"\n") "\";")),
+///     r#"let s = "\"hi\"\n";"#
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::quote_str;
+///
+/// let s = quote_str!(a);
+/// ```
+pub use place_macro_proc::quote_str;
+
+/// Expands to the length (in characters) of the longest of the comma
+/// separated string literals. Expands to `0` if there are no literals.
+///
+/// # Examples
+/// ```
+/// use place_macro::{max_len, place};
+///
+/// assert_eq!(max_len!("a", "abc", "ab"), 3);
+/// assert_eq!(max_len!("ab", "čšř", "🦀",), 3);
+/// assert_eq!(max_len!(), 0);
+///
+/// let width = place!(__max_len__("name", "value", __string__(id)));
+/// assert_eq!(format!("{:>width$}|", "id"), "   id|");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::max_len;
+///
+/// let n = max_len!("a", b);
+/// ```
+pub use place_macro_proc::max_len;
+
+/// Same as `stringify_pretty`, but the string is in backticks so that it is
+/// code in markdown (e.g. in doc comments).
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, to_doc};
+///
+/// assert_eq!(to_doc!(Vec<u32>), "`Vec<u32>`");
+/// assert_eq!(to_doc!(&'a mut HashMap<K,V>), "`&'a mut HashMap<K, V>`");
+/// assert_eq!(to_doc!(), "``");
+///
+/// macro_rules! getter {
+///     ($name:ident: $t:ty) => {
+///         place! {
+///             #[doc = __string__("Returns " __to_doc__($t) ".")]
+///             fn $name() -> $t {
+///                 Default::default()
+///             }
+///         }
+///     };
+/// }
+///
+/// getter!(values: Vec<Option<u8>>);
+/// assert_eq!(values(), vec![]);
+/// assert_eq!(
+///     place!(__string__("Returns " __to_doc__(Option<u8>) ".")),
+///     "Returns `Option<u8>`."
+/// );
+/// ```
+pub use place_macro_proc::to_doc;
+
+/// Repeats the group the given number of times. The groups keep their
+/// delimiters.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, repeat_group};
+///
+/// assert_eq!(
+///     place!(__stringify__(__repeat_group__(3, [a, b]))),
+///     "[a, b] [a, b] [a, b]"
+/// );
+/// assert_eq!(place!(__stringify__(__repeat_group__(0, (a)))), "");
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Cell {
+///     value: u8,
+/// }
+///
+/// macro_rules! cells {
+///     ($($c:tt)*) => {
+///         [$(Cell $c),*]
+///     };
+/// }
+///
+/// let c = place!(cells!(__repeat_group__(2, { value: 7 })));
+/// assert_eq!(c, [Cell { value: 7 }, Cell { value: 7 }]);
+///
+/// let s: (u8,) = repeat_group!(1, (5,));
+/// assert_eq!(s, (5,));
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::repeat_group;
+///
+/// let a = [repeat_group!(2, a)];
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::repeat_group;
+///
+/// let a = [repeat_group!(1000000000, (1,))];
+/// ```
+pub use place_macro_proc::repeat_group;
+
+/// Reverses the order of the segments in path. Leading `::` stays at the
+/// start. Generic arguments are ignored in the same way as in `path_last`.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, reverse_path};
+///
+/// assert_eq!(place!(__stringify__(__reverse_path__(a::b::c))), "c :: b :: a");
+/// assert_eq!(
+///     place!(__stringify__(__reverse_path__(::std::vec::Vec<u8>))),
+///     ":: Vec :: vec :: std"
+/// );
+/// assert_eq!(place!(__stringify__(__reverse_path__(a))), "a");
+///
+/// mod c {
+///     pub mod b {
+///         pub fn a() -> u8 {
+///             5
+///         }
+///     }
+/// }
+///
+/// assert_eq!(reverse_path!(a::b::c)(), 5);
+/// ```
+pub use place_macro_proc::reverse_path;
+
+/// Splits the input by commas and puts each of the parts into parenthesis.
+/// Empty parts produce empty group, but trailing comma is ignored.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(
+///     place!(__stringify__(__group_args__(a b, c, d e))),
+///     "(a b) (c) (d e)"
+/// );
+/// assert_eq!(place!(__stringify__(__group_args__(a,, b,))), "(a) () (b)");
+/// assert_eq!(place!(__stringify__(__group_args__())), "");
+///
+/// macro_rules! sum {
+///     ($(($($n:literal)*))*) => {
+///         [$(0 $(+ $n)*),*]
+///     };
+/// }
+///
+/// assert_eq!(place!(sum!(__group_args__(1 2, 3, , 4 5 6))), [3, 3, 0, 15]);
+/// ```
+pub use place_macro_proc::group_args;
+
+/// Expands the template (first group) for each pair of tokens from the
+/// second and third group. In the template, `__0__` is replaced with the
+/// token from the second group and `__1__` with the token from the third
+/// group. The second and third group must have the same number of tokens.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(
+///     place!(__stringify__(__zip_with__((__0__: __1__), (a b), (u8 u16)))),
+///     "a : u8 b : u16"
+/// );
+///
+/// place! {
+///     struct Point {
+///         __zip_with__((pub __0__: __1__,), (x y), (i32 i64))
+///     }
+/// }
+///
+/// let p = Point { x: 1, y: 2 };
+/// assert_eq!((p.x, p.y), (1i32, 2i64));
+/// assert_eq!(
+///     place!(__stringify__(__zip_with__(([__1__, __0__]), (a b), (c d)))),
+///     "[c, a] [d, b]"
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(__zip_with__((__0__: __1__), (a b c), (u8 u16)));
+/// ```
+pub use place_macro_proc::zip_with;
+
+/// Repeatedly removes the pattern (second string literal) from the start of
+/// the first string literal.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, trim_start_matches};
+///
+/// assert_eq!(trim_start_matches!("__name", "_"), "name");
+/// assert_eq!(trim_start_matches!("ababc", "ab"), "c");
+/// assert_eq!(trim_start_matches!("name_", "_"), "name_");
+/// assert_eq!(place!(__trim_start_matches__("name", "")), "name");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::trim_start_matches;
+///
+/// let s = trim_start_matches!("__a", _);
+/// ```
+pub use place_macro_proc::trim_start_matches;
+
+/// Repeatedly removes the pattern (second string literal) from the end of
+/// the first string literal.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, trim_end_matches};
+///
+/// assert_eq!(trim_end_matches!("name__", "_"), "name");
+/// assert_eq!(trim_end_matches!("a::::", "::"), "a");
+/// assert_eq!(trim_end_matches!("_name", "_"), "_name");
+/// let get_x = 5;
+/// assert_eq!(
+///     place!(__identifier__(__trim_end_matches__("get__", "_") _x)),
+///     5
+/// );
+/// ```
+pub use place_macro_proc::trim_end_matches;
+
+/// Centers the string literal to the given width (in characters) with the
+/// fill character (given as string literal). If the padding is odd, the
+/// extra character is on the right. Strings that are already at least as
+/// long as the width are not changed. The width may be at most 65536.
+///
+/// # Examples
+/// ```
+/// use place_macro::{center, place};
+///
+/// assert_eq!(center!("ab", 6, "*"), "**ab**");
+/// assert_eq!(center!("ab", 5, "-"), "-ab--");
+/// assert_eq!(center!("long", 2, " "), "long");
+/// assert_eq!(
+///     place!(__center__(__string__(" " Title " "), 11, "=")),
+///     "== Title =="
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::center;
+///
+/// let s = center!("ab", 6, "**");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::center;
+///
+/// let s = center!("ab", 1000000000, "*");
+/// ```
+pub use place_macro_proc::center;
+
+/// Escapes the string literal so that it can be used inside string in JSON
+/// (without the surrounding quotes).
+///
+/// # Examples
+/// ```
+/// use place_macro::{json_escape, place};
+///
+/// assert_eq!(json_escape!(r#"say "hi""#), r#"say \"hi\""#);
+/// assert_eq!(json_escape!(r"C:\dir"), r"C:\\dir");
+/// assert_eq!(json_escape!("a\nb\tc"), r"a\nb\tc");
+/// assert_eq!(json_escape!("\u{1}ž"), r"\u0001ž");
+/// assert_eq!(
+///     place!(__string__(r#"{"name": ""# __json_escape__("a\"b") r#""}"#)),
+///     r#"{"name": "a\"b"}"#
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::json_escape;
+///
+/// let s = json_escape!(5);
+/// ```
+pub use place_macro_proc::json_escape;
+
+/// Expands to comma separated integers from `start - 1` down to `end`
+/// (arguments are `start, end`), it is the same as `(end..start).rev()`.
+/// Expands to nothing if `start` is not larger than `end`. The range may have
+/// at most 65536 items.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!([__range_rev__(4, 0)]), [3, 2, 1, 0]);
+/// assert_eq!(place!([__range_rev__(1, -2)]), [0, -1, -2]);
+/// assert_eq!(place!(__stringify__(__range_rev__(2, 2))), "");
+///
+/// let t = (1, 2, 3);
+/// macro_rules! rev_tuple {
+///     ($t:ident: $($i:tt),*) => {
+///         ($($t.$i),*)
+///     };
+/// }
+/// assert_eq!(place!(rev_tuple!(t: __range_rev__(3, 0))), (3, 2, 1));
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::range_rev;
+///
+/// let a = [range_rev!(1000000000, 0)];
+/// ```
+pub use place_macro_proc::range_rev;
+
+/// Expands to the given number of comma separated names for generic
+/// parameters. If there is second argument (string literal), it is used as
+/// prefix and the names are numbered from 0 (e.g. `T0, T1, T2`), otherwise
+/// the names are the letters `A`, `B`, `C`, ... (at most 26). With prefix,
+/// there may be at most 65536 names.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(
+///     place!(__stringify__(__type_param_names__(3, "T"))),
+///     "T0, T1, T2"
+/// );
+/// assert_eq!(place!(__stringify__(__type_param_names__(3))), "A, B, C");
+/// assert_eq!(place!(__stringify__(__type_param_names__(0))), "");
+///
+/// trait Count {
+///     const COUNT: usize;
+/// }
+///
+/// macro_rules! impl_count {
+///     ($n:literal) => {
+///         place! {
+///             impl<__type_param_names__($n)> Count
+///                 for (__type_param_names__($n),)
+///             {
+///                 const COUNT: usize = $n;
+///             }
+///         }
+///     };
+/// }
+///
+/// impl_count!(2);
+/// impl_count!(3);
+/// assert_eq!(<(u8, bool, char) as Count>::COUNT, 3);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(fn foo<__type_param_names__(27)>() {});
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(fn foo<__type_param_names__(1000000000, "T")>() {});
+/// ```
+pub use place_macro_proc::type_param_names;
+
+/// Removes `//` and `/* */` comments from the string literal. The newline
+/// after line comment is kept.
+///
+/// This is naive, comment sequences are removed even if they are inside
+/// string literals in the text.
+///
+/// # Examples
+/// ```
+/// use place_macro::strip_comments;
+///
+/// assert_eq!(
+///     strip_comments!("let a = 5; // five\nlet b = 6;\n"),
+///     "let a = 5; \nlet b = 6;\n"
+/// );
+/// assert_eq!(strip_comments!("a /* b /* c */ d */ e"), "a  e");
+/// assert_eq!(strip_comments!("no comments"), "no comments");
+/// assert_eq!(strip_comments!("a// end"), "a");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::strip_comments;
+///
+/// let s = strip_comments!(a);
+/// ```
+pub use place_macro_proc::strip_comments;
+
+/// Expands to comma separated char literals from the first to the second
+/// char literal (inclusive). The chars are in the order of their unicode
+/// scalar values. It is error if the first char is larger than the second.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!([__char_range__('a', 'e')]), ['a', 'b', 'c', 'd', 'e']);
+/// assert_eq!(place!([__char_range__('7', '9')]), ['7', '8', '9']);
+/// assert_eq!(place!([__char_range__('x', 'x')]), ['x']);
+///
+/// const HEX: [char; 16] =
+///     place!([__char_range__('0', '9'), __char_range__('a', 'f')]);
+/// assert_eq!(HEX[11], 'b');
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// let a = place!([__char_range__('z', 'a')]);
+/// ```
+pub use place_macro_proc::char_range;
+
+/// Concatenates the literals. If all the tokens are integer literals (and
+/// there is at least one), the result is unsuffixed integer literal with the
+/// digits of the decimal values of the literals (suffixes are ignored).
+/// Otherwise the result is string literal, the same as with `string`.
+///
+/// Inside `place` it is also available as `__lit_concat__`.
+///
+/// # Examples
+/// ```
+/// use place_macro::{literal_concat, place};
+///
+/// assert_eq!(literal_concat!(1 2 3), 123);
+/// assert_eq!(literal_concat!(4 0x10 5u8), 4165);
+/// assert_eq!(literal_concat!(1 "a" 2), "1a2");
+/// assert_eq!(literal_concat!(a 1), "a1");
+/// assert_eq!(literal_concat!(), "");
+/// assert_eq!(place!(__lit_concat__(12 34) + 1), 1235);
+/// ```
+pub use place_macro_proc::literal_concat;
+
+/// Expands to the first token after the count followed by the rest of the
+/// tokens repeated count times (arguments are `count, first rest...`).
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(__repeat_tail__(0, a b c))), "a");
+/// assert_eq!(place!(__stringify__(__repeat_tail__(1, a b c))), "a b c");
+/// assert_eq!(
+///     place!(__stringify__(__repeat_tail__(3, f (x)))),
+///     "f(x) (x) (x)"
+/// );
+///
+/// place! {
+///     struct Rgb(__repeat_tail__(2, u8, u8));
+/// }
+///
+/// let c = Rgb(1, 2, 3);
+/// assert_eq!(c.0 + c.1 + c.2, 6);
+/// ```
+pub use place_macro_proc::repeat_tail;
+
+/// Prepends the prefix (second string literal) to each line of the first
+/// string literal. Blank lines are not changed unless there is third
+/// argument `"all"`.
+///
+/// # Examples
+/// ```
+/// use place_macro::{indent, place};
+///
+/// assert_eq!(indent!("a\n\nb\n", "    "), "    a\n\n    b\n");
+/// assert_eq!(indent!("a\n\nb", "> ", "all"), "> a\n> \n> b");
+/// assert_eq!(indent!("", "  "), "");
+/// let code = place!(__string__(
+///     "fn main() {\n" __indent__("foo();\nbar();", "\t") "\n}"
+/// ));
+/// assert_eq!(code, "fn main() {\n\tfoo();\n\tbar();\n}");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::indent;
+///
+/// let s = indent!("a", "  ", "none");
+/// ```
+pub use place_macro_proc::indent;
+
+/// Replaces the token at the given index with the contents of the group
+/// (arguments are `index, (replacement), tokens...`).
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(__replace_nth__(0, (x), a b c))), "x b c");
+/// assert_eq!(
+///     place!(__stringify__(__replace_nth__(1, (x y), a b c))),
+///     "a x y c"
+/// );
+/// assert_eq!(place!(__stringify__(__replace_nth__(2, (), a b c))), "a b");
+/// assert_eq!(place!(__replace_nth__(1, (*), 3 + 4)), 12);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(__replace_nth__(3, (x), a b c));
+/// ```
+pub use place_macro_proc::replace_nth;
+
+/// Inserts the contents of the group before the token at the given index
+/// (arguments are `index, (insertion), tokens...`). If the index is equal to
+/// the number of tokens, the insertion is at the end.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(__insert_at__(0, (x), a b))), "x a b");
+/// assert_eq!(place!(__stringify__(__insert_at__(1, (x y), a b))), "a x y b");
+/// assert_eq!(place!(__stringify__(__insert_at__(2, (x), a b))), "a b x");
+/// assert_eq!(place!(__insert_at__(1, (+ 2), 1 * 3)), 7);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(__insert_at__(3, (x), a b));
+/// ```
+pub use place_macro_proc::insert_at;
+
+/// Removes the token at the given index (arguments are `index, tokens...`).
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(__remove_at__(0, a b c))), "b c");
+/// assert_eq!(place!(__stringify__(__remove_at__(1, a b c))), "a c");
+/// assert_eq!(place!(__stringify__(__remove_at__(2, a b c))), "a b");
+/// assert_eq!(place!(__remove_at__(1, 5 - + 3)), 8);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(__remove_at__(3, a b c));
+/// ```
+pub use place_macro_proc::remove_at;
+
+/// Transposes matrix of tokens, the rows are given as groups in parenthesis
+/// and the result are the columns in parenthesis. All the rows must have
+/// the same number of tokens.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(
+///     place!(__stringify__(__transpose__((a b) (c d)))),
+///     "(a c) (b d)"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__transpose__((a b) (c d) (e f)))),
+///     "(a c e) (b d f)"
+/// );
+///
+/// macro_rules! names {
+///     ($(($($n:ident)*))*) => {
+///         [$(stringify!($($n)*)),*]
+///     };
+/// }
+///
+/// assert_eq!(
+///     place!(names!(__transpose__((x y z) (r g b)))),
+///     ["x r", "y g", "z b"]
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(__transpose__((a b) (c)));
+/// ```
+pub use place_macro_proc::transpose;
+
+/// Expands to integer literal with the given number of low bits set. The
+/// optional second argument is shift to the left.
+///
+/// # Examples
+/// ```
+/// use place_macro::{mask, place};
+///
+/// assert_eq!(mask!(3), 7);
+/// assert_eq!(mask!(3, 4), 112);
+/// assert_eq!(mask!(0), 0);
+/// assert_eq!(mask!(0, 128), 0);
+/// assert_eq!(mask!(8, 8), 0xff00);
+/// assert_eq!(mask!(128), u128::MAX);
+/// assert_eq!(mask!(1, 127), 1u128 << 127);
+/// assert_eq!(place!(__mask__(__bit_width__(5))), 7);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::mask;
+///
+/// let m = mask!(129);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::mask;
+///
+/// let m = mask!(8, 121);
+/// ```
+pub use place_macro_proc::mask;
+
+/// Removes generic arguments (everything in `<` `>`, including the `::` of
+/// turbofish) from type or path. The `<` and `>` are not groups, but they
+/// must be balanced.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(
+///     place!(__stringify__(__strip_generics__(HashMap<K, V>))),
+///     "HashMap"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__strip_generics__(std::vec::Vec<Vec<u8>>))),
+///     "std :: vec :: Vec"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__strip_generics__(Box<dyn Fn(u8) -> u8>))),
+///     "Box"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__strip_generics__(a::B<T>::new::<u8>))),
+///     "a :: B :: new"
+/// );
+/// assert_eq!(place!(__stringify__(__strip_generics__(String))), "String");
+///
+/// let v: Vec<i32> = place!(__strip_generics__(Vec<u8>)::new());
+/// assert!(v.is_empty());
+/// ```
+pub use place_macro_proc::strip_generics;
+
+/// Puts the first top level `<...>` (including the `<` and `>`) into
+/// invisible group, so that other macros can work with it as with single
+/// token. The `<` and `>` are matched by counting the nesting, `>` that is
+/// part of `->` is not counted. If there is no `<...>`, the tokens are not
+/// changed.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(
+///     place!(__stringify__(__last__(__angle_group__(Vec<Vec<u8>>)))),
+///     "< Vec < u8 >>"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__last__(__angle_group__(F<A<<B as C>::D>, E>)))),
+///     "< A << B as C >:: D >, E >"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__last__(__angle_group__(Box<dyn Fn() -> u8>)))),
+///     "< dyn Fn() -> u8 >"
+/// );
+/// assert_eq!(place!(__stringify__(__last__(__angle_group__(a < b)))), "b");
+/// assert_eq!(
+///     place!(__stringify__(__last__(__angle_group__(a < b, Vec<u8>)))),
+///     "< u8 >"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__head__(__angle_group__(<T, U> where)))),
+///     "< T, U >"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__type_params__(__tail__(__angle_group__(
+///         Foo<T: Into<Vec<u8>>, const N: usize>
+///     ))))),
+///     "T, N"
+/// );
+/// ```
+pub use place_macro_proc::angle_group;
+
+/// Repeats the string literal the given number of times and concatenates it
+/// into single string literal. `{i}` in the string is replaced with the
+/// index of the repetition (starting at 0). The count may be at most 65536.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, repeat_concat};
+///
+/// assert_eq!(repeat_concat!(3, "x{i} "), "x0 x1 x2 ");
+/// assert_eq!(repeat_concat!(3, "ab"), "ababab");
+/// assert_eq!(repeat_concat!(0, "{i}"), "");
+/// assert_eq!(
+///     place!(__trim_end_matches__(__repeat_concat__(3, "?{i}, "), ", ")),
+///     "?0, ?1, ?2"
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::repeat_concat;
+///
+/// let s = repeat_concat!(3, x);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::repeat_concat;
+///
+/// let s = repeat_concat!(1000000000, "x");
+/// ```
+pub use place_macro_proc::repeat_concat;
+
+/// Expands to the longest common prefix of the comma separated string
+/// literals. Expands to empty string if there are no literals.
+///
+/// # Examples
+/// ```
+/// use place_macro::{common_prefix, place};
+///
+/// assert_eq!(common_prefix!("key_up", "key_down", "key_left"), "key_");
+/// assert_eq!(common_prefix!("abc", "xyz"), "");
+/// assert_eq!(common_prefix!("only"), "only");
+/// assert_eq!(common_prefix!(), "");
+/// assert_eq!(
+///     place!(__common_prefix__(__string__(ab c), "abd")),
+///     "ab"
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::common_prefix;
+///
+/// let s = common_prefix!("a", b);
+/// ```
+pub use place_macro_proc::common_prefix;
+
+/// Capitalizes the first letter of each word (separated by whitespace) in
+/// the string literal. The whitespace is not changed. With second argument
+/// `"small"`, the words `a`, `an` and `the` are lowercase (except the first
+/// word).
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, title_case};
+///
+/// assert_eq!(title_case!("hello world"), "Hello World");
+/// assert_eq!(title_case!("  padded\ttext "), "  Padded\tText ");
+/// assert_eq!(title_case!("a tale of the city"), "A Tale Of The City");
+/// assert_eq!(
+///     title_case!("a tale of THE city", "small"),
+///     "A Tale Of the City"
+/// );
+/// assert_eq!(place!(__title_case__(__string__("user " name))), "User Name");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::title_case;
+///
+/// let s = title_case!("hello", "big");
+/// ```
+pub use place_macro_proc::title_case;
+
+/// Expands to the tokens after the count if there are exactly count tokens
+/// (groups are single token), otherwise it is error (arguments are
+/// `count, tokens...`).
+///
+/// # Examples
+/// ```
+/// use place_macro::{assert_count, place};
+///
+/// assert_eq!(assert_count!(3, 1 + 2), 3);
+/// assert_eq!(place!(__assert_count__(1, (1 + 2)) * 2), 6);
+///
+/// // commas are also tokens
+/// assert_eq!(place!([__assert_count__(5, 1, 2, 3)]), [1, 2, 3]);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::assert_count;
+///
+/// let a = assert_count!(3, 1 + 2 + 3);
+/// ```
+pub use place_macro_proc::assert_count;
+
+/// Expands to the tokens at the indexes in the first group (arguments are
+/// `(indexes...), tokens...`). The indexes may be in any order and they may
+/// repeat.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(__pick__((2 0), a b c))), "c a");
+/// assert_eq!(place!(__stringify__(__pick__((1 1 0 1), a b))), "b b a b");
+/// assert_eq!(place!(__stringify__(__pick__((), a b))), "");
+///
+/// fn sub(a: i32, b: i32) -> i32 {
+///     a - b
+/// }
+///
+/// assert_eq!(place!(sub(__pick__((2 1 0), 5, 3))), -2);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(__pick__((3), a b c));
+/// ```
+pub use place_macro_proc::pick;
+
+/// Splits the tokens into chunks of the given size and puts each chunk into
+/// group (arguments are `size, delimiter, tokens...`). The delimiter is one
+/// of `"()"`, `"[]"`, `"{}"` or `""` (invisible group). The last chunk may
+/// be smaller.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(
+///     place!(__stringify__(__group_every__(2, "()", a b c d))),
+///     "(a b) (c d)"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__group_every__(2, "[]", a b c))),
+///     "[a b] [c]"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__group_every__(3, "{}", a b c d))),
+///     "{ a b c } { d }"
+/// );
+/// assert_eq!(place!(__stringify__(__group_every__(1, "", a b))), "a b");
+///
+/// macro_rules! pairs {
+///     ($(($a:literal $b:literal))*) => {
+///         [$(($a, $b)),*]
+///     };
+/// }
+///
+/// assert_eq!(
+///     place!(pairs!(__group_every__(2, "()", 1 2 3 4))),
+///     [(1, 2), (3, 4)]
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(__group_every__(0, "()", a b));
+/// ```
+pub use place_macro_proc::group_every;
+
+/// Expands to string literal with the value of the environment variable
+/// (first argument). If the variable is not set, expands to the contents of
+/// the file (second argument) instead. The path to the file is relative to
+/// the directory with `Cargo.toml` of the crate that is being compiled. It
+/// is error if the variable is not set and the file cannot be read.
+///
+/// This macro is available only with the feature `std`.
+///
+/// # Examples
+/// ```
+/// use place_macro::env_or_file;
+///
+/// assert_eq!(env_or_file!("CARGO_PKG_NAME", "Cargo.toml"), "place_macro");
+///
+/// let manifest = env_or_file!("PLACE_MACRO_NOT_SET", "Cargo.toml");
+/// assert!(manifest.starts_with("[package]"));
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::env_or_file;
+///
+/// let s = env_or_file!("PLACE_MACRO_NOT_SET", "not-a-file.txt");
+/// ```
+#[cfg(feature = "std")]
+pub use place_macro_proc::env_or_file;
+
+/// Expands the template (first group) for each of the following groups. In
+/// the template, `__x__` is replaced with the contents of the group (arguments
+/// are `(template), (element) (element)...`).
+///
+/// Inside `place`, the macros in the template are expanded before the
+/// replacement, so they see `__x__` and not the elements.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(
+///     place!(__stringify__(__for_each__((__x__ + 1;), (a) (b c)))),
+///     "a + 1; b c + 1;"
+/// );
+///
+/// place! {
+///     __for_each__(
+///         (fn __x__() -> &'static str { stringify!(__x__) }),
+///         (foo) (bar) (baz)
+///     )
+/// }
+///
+/// assert_eq!(foo(), "foo");
+/// assert_eq!(bar(), "bar");
+/// assert_eq!(baz(), "baz");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(__for_each__((__x__;), a b));
+/// ```
+pub use place_macro_proc::for_each;
+
+/// Cleans up multiline string literal (e.g. for documentation):
+/// 1. Whitespace at the end of each line is removed.
+/// 2. Empty lines at the start and at the end are removed.
+/// 3. The indentation that is common to all the non-empty lines is removed.
+///
+/// The result doesn't end with newline.
+///
+/// # Examples
+/// ```
+/// use place_macro::{clean_doc, place};
+///
+/// assert_eq!(
+///     clean_doc!(
+///         "
+///         Title
+///
+///             indented
+///         end
+///         "
+///     ),
+///     "Title\n\n    indented\nend"
+/// );
+/// assert_eq!(clean_doc!("  a  \n\t\n    b\t\n"), "a\n\n  b");
+/// assert_eq!(clean_doc!(" \n \n"), "");
+///
+/// place! {
+///     #[doc = __clean_doc__("
+///         Returns five.
+///     ")]
+///     fn five() -> u8 {
+///         5
+///     }
+/// }
+/// assert_eq!(five(), 5);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::clean_doc;
+///
+/// let s = clean_doc!(doc);
+/// ```
+pub use place_macro_proc::clean_doc;
+
+/// Replaces all the non-ASCII characters in the string literal with the
+/// text of their escape sequence (e.g. `é` becomes the 8 characters
+/// `\u{e9}`), so the result contains only ASCII characters.
+///
+/// # Examples
+/// ```
+/// use place_macro::{escape_unicode, place};
+///
+/// assert_eq!(escape_unicode!("café"), r"caf\u{e9}");
+/// assert_eq!(escape_unicode!("🦀!"), r"\u{1f980}!");
+/// assert_eq!(escape_unicode!("ascii\n"), "ascii\n");
+/// assert_eq!(
+///     place!(__escape_unicode__(__string__(ž "ř"))),
+///     r"\u{17e}\u{159}"
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::escape_unicode;
+///
+/// let s = escape_unicode!('é');
+/// ```
+pub use place_macro_proc::escape_unicode;
+
+/// Expands to the first token and the rest of the tokens, each in
+/// parenthesis: `(first) (rest...)`. Empty input expands to `() ()`.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(__split_first__(a b c))), "(a) (b c)");
+/// assert_eq!(place!(__stringify__(__split_first__(a))), "(a) ()");
+/// assert_eq!(place!(__stringify__(__split_first__())), "() ()");
+/// assert_eq!(
+///     place!(__stringify__(__split_first__((a b) c))),
+///     "((a b)) (c)"
+/// );
+///
+/// macro_rules! call {
+///     (($f:ident) ($($arg:expr)*)) => {
+///         $f($($arg),*)
+///     };
+/// }
+///
+/// fn add(a: i32, b: i32) -> i32 {
+///     a + b
+/// }
+///
+/// assert_eq!(place!(call!(__split_first__(add 1 2))), 3);
+/// ```
+pub use place_macro_proc::split_first;
+
+/// Expands to all the tokens but the last and the last token, each in
+/// parenthesis: `(start...) (last)`. Empty input expands to `() ()`.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(__split_last__(a b c))), "(a b) (c)");
+/// assert_eq!(place!(__stringify__(__split_last__(a))), "() (a)");
+/// assert_eq!(place!(__stringify__(__split_last__())), "() ()");
+/// assert_eq!(
+///     place!(__stringify__(__split_last__(a (b c)))),
+///     "(a) ((b c))"
+/// );
+///
+/// macro_rules! last_arg {
+///     (($($init:tt)*) ($last:expr)) => {
+///         $last
+///     };
+/// }
+///
+/// assert_eq!(place!(last_arg!(__split_last__(1 2 3))), 3);
+/// ```
+pub use place_macro_proc::split_last;
+
+/// Splits the tokens at the first occurrence of the separator (arguments
+/// are `separator, tokens...`) and expands to the tokens before and after it
+/// in parenthesis: `(before...) (after...)`. The separator is single
+/// identifier or punctuation character and it is not in the result. If the
+/// separator is not found, expands to `(tokens...) ()`.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(
+///     place!(__stringify__(__partition__(:, name: Vec<u8>))),
+///     "(name) (Vec < u8 >)"
+/// );
+/// assert_eq!(
+///     place!(__stringify__(__partition__(as, a as b as c))),
+///     "(a) (b as c)"
+/// );
+/// assert_eq!(place!(__stringify__(__partition__(=, a b))), "(a b) ()");
+///
+/// macro_rules! field {
+///     (($name:ident) ($t:ty)) => {
+///         struct Wrapper {
+///             $name: $t,
+///         }
+///     };
+/// }
+///
+/// place!(field!(__partition__(:, value: u8)));
+/// assert_eq!(Wrapper { value: 5 }.value, 5);
+/// ```
+pub use place_macro_proc::partition;
+
+/// Counts the occurrences of character (given as string literal with single
+/// character) in string literal.
+///
+/// # Examples
+/// ```
+/// use place_macro::{count_char, place};
+///
+/// assert_eq!(count_char!("hello", "z"), 0);
+/// assert_eq!(count_char!("hello", "h"), 1);
+/// assert_eq!(count_char!("hello", "l"), 2);
+/// assert_eq!(count_char!("{a} {b} {c}", "{"), 3);
+/// assert_eq!(count_char!("čččc", "č"), 3);
+///
+/// const _: () = assert!(
+///     place!(__count_char__("{{a}}", "{"))
+///         == place!(__count_char__("{{a}}", "}"))
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::count_char;
+///
+/// let n = count_char!("hello", "ll");
+/// ```
+pub use place_macro_proc::count_char;
+
+/// Repeats the tokens until there is the given number of tokens (arguments
+/// are `count, tokens...`). The last repetition may be incomplete. The count
+/// may be at most 65536.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(__cycle__(5, a b))), "a b a b a");
+/// assert_eq!(place!(__stringify__(__cycle__(2, a b c))), "a b");
+/// assert_eq!(place!(__stringify__(__cycle__(3, a b c))), "a b c");
+/// assert_eq!(place!(__stringify__(__cycle__(0, a))), "");
+/// assert_eq!(place!([__cycle__(6, 0,)]), [0; 3]);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(__cycle__(3,));
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// let a = place!([__cycle__(1000000000, 0,)]);
+/// ```
+pub use place_macro_proc::cycle;
+
+/// If the first group is not empty, expands to the contents of the second
+/// group with `__it__` replaced with the contents of the first group.
+/// Otherwise expands to nothing.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(
+///     place!(__stringify__(__if_present__((a b), (x = __it__;)))),
+///     "x = a b;"
+/// );
+/// assert_eq!(place!(__stringify__(__if_present__((), (x = __it__;)))), "");
+///
+/// macro_rules! item {
+///     ($name:ident $(, $doc:literal)?) => {
+///         place! {
+///             __if_present__(($($doc)?), (#[doc = __it__]))
+///             pub struct $name;
+///         }
+///     };
+/// }
+///
+/// item!(Documented, "Has documentation.");
+/// item!(Undocumented);
+/// let _ = (Documented, Undocumented);
+/// ```
+pub use place_macro_proc::if_present;
+
+/// Expands to comma separated identifiers created from the identifier and
+/// numbers from 0 to the count (exclusive), e.g. `a, 3` expands to
+/// `a0, a1, a2`. The count may be at most 65536.
+///
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(__numbered_idents__(a, 0))), "");
+/// assert_eq!(place!(__stringify__(__numbered_idents__(a, 1))), "a0");
+/// assert_eq!(
+///     place!(__stringify__(__numbered_idents__(arg, 3))),
+///     "arg0, arg1, arg2"
+/// );
+///
+/// place! {
+///     fn sum3((__numbered_idents__(x, 3)): (i32, i32, i32)) -> i32 {
+///         x0 + x1 + x2
+///     }
+/// }
+/// assert_eq!(sum3((1, 2, 3)), 6);
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(__numbered_idents__("a", 3));
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place!(__numbered_idents__(a, 1000000000));
+/// ```
+pub use place_macro_proc::numbered_idents;
+
+/// Expands to the longest (in characters) of the comma separated string
+/// literals. If there are more longest literals, the first one is used.
+///
+/// # Examples
+/// ```
+/// use place_macro::{longest, place};
+///
+/// assert_eq!(longest!("id", "identifier", "ident"), "identifier");
+/// assert_eq!(longest!("ab", "cd", "e"), "ab");
+/// assert_eq!(longest!("only"), "only");
+/// assert_eq!(longest!("žžž", "abcd"), "abcd");
+/// assert_eq!(place!(__longest__(__string__(a b c), "xy")), "abc");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::longest;
+///
+/// let s = longest!();
+/// ```
+pub use place_macro_proc::longest;
+
+/// Expands to the shortest (in characters) of the comma separated string
+/// literals. If there are more shortest literals, the first one is used.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, shortest};
+///
+/// assert_eq!(shortest!("identifier", "id", "ident"), "id");
+/// assert_eq!(shortest!("a", "b", "cd"), "a");
+/// assert_eq!(shortest!("only"), "only");
+/// assert_eq!(shortest!("ab", "ččč"), "ab");
+/// assert_eq!(place!(__shortest__("long", __string__(x))), "x");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::shortest;
+///
+/// let s = shortest!("a", b);
+/// ```
+pub use place_macro_proc::shortest;
+
+/// Converts kebab case string literal to snake case by replacing all `-`
+/// with `_`. Unlike [`snake`], it doesn't change anything else.
+///
+/// # Examples
+/// ```
+/// use place_macro::{kebab_to_snake, place};
+///
+/// assert_eq!(kebab_to_snake!("log-level"), "log_level");
+/// assert_eq!(kebab_to_snake!("no-color-output"), "no_color_output");
+/// assert_eq!(kebab_to_snake!("plain"), "plain");
+/// assert_eq!(place!(__kebab_to_snake__("a-B-c")), "a_B_c");
+/// ```
+pub use place_macro_proc::kebab_to_snake;
+
+/// Converts snake case string literal to kebab case by replacing all `_`
+/// with `-`. Unlike [`kebab`], it doesn't change anything else.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, snake_to_kebab};
+///
+/// assert_eq!(snake_to_kebab!("log_level"), "log-level");
+/// assert_eq!(snake_to_kebab!("no_color_output"), "no-color-output");
+/// assert_eq!(snake_to_kebab!("plain"), "plain");
+/// assert_eq!(
+///     place!(__snake_to_kebab__(__string__(no_ __snake__(DryRun)))),
+///     "no-dry-run"
+/// );
+/// ```
+pub use place_macro_proc::snake_to_kebab;
+
+/// Converts string literal with path to snake case by replacing all `::`
+/// with `_`.
+///
+/// # Examples
+/// ```
+/// use place_macro::{path_to_snake, place};
+///
+/// assert_eq!(path_to_snake!("std::io::stdin"), "std_io_stdin");
+/// assert_eq!(path_to_snake!("config::log_level"), "config_log_level");
+/// assert_eq!(path_to_snake!("main"), "main");
+/// assert_eq!(
+///     place!(__path_to_snake__(__stringify_pretty__(core::mem::swap))),
+///     "core_mem_swap"
+/// );
+/// ```
+pub use place_macro_proc::path_to_snake;
+
+/// Repeatedly removes the character from both ends of string literal. The
+/// character is given as string literal with single character.
+///
+/// Inside `place` it is also available as `__trim_matches_char__`.
+///
+/// # Examples
+/// ```
+/// use place_macro::{place, trim_char};
+///
+/// assert_eq!(trim_char!("__private__", "_"), "private");
+/// assert_eq!(trim_char!("\"quoted\"", "\""), "quoted");
+/// assert_eq!(trim_char!("name__", "_"), "name");
+/// assert_eq!(trim_char!("__name", "_"), "name");
+/// assert_eq!(trim_char!("snake_case", "_"), "snake_case");
+/// assert_eq!(trim_char!("plain", "_"), "plain");
+/// assert_eq!(place!(__trim_char__(__string__(_a _), "_")), "a");
+/// assert_eq!(place!(__trim_matches_char__("'x'", "'")), "x");
+/// ```
+///
+/// ```compile_fail
+/// use place_macro::trim_char;
+///
+/// let s = trim_char!("--x--", "--");
+/// ```
+pub use place_macro_proc::trim_char;
+
+/// Evaluates the macros in this crate in reverse order
+///
+/// to minimize conflicts, the macros are refered to as `__macro__` where
+/// macro is the name of the macro. Special case is the macro `dollar` that
+/// doesn't have any arguments.
+/// # Examples
+/// ```
+/// use place_macro::place;
+///
+/// place! {
+///     pub fn __identifier__(my "_function")() -> bool {
+///         true
+///     }
+/// }
+/// assert!(my_function());
+///
+/// place! {
+///     macro_rules! mac {
+///         (__dollar__ var: literal) => {
+///             __dollar__ var
+///         }
+///     }
+/// }
+/// assert_eq!("hi", mac!("hi"));
+///
+/// let res = place!(__string__(1 __string__(2 __identity__(3 __string__(4)))));
+/// assert_eq!(res, "123__string__4");
+/// ```
+///
+/// The brackets used to invoke `place` don't matter, they are not part of the
+/// input:
+/// ```
+/// use place_macro::place;
+///
+/// let a = place!(__stringify__(a (b __ident__(c d)) [e] {f}));
+/// let b = place![__stringify__(a (b __ident__(c d)) [e] {f})];
+/// let c = place! {__stringify__(a (b __ident__(c d)) [e] {f})};
+/// assert!(a.contains("cd"));
+/// assert_eq!(a, b);
+/// assert_eq!(a, c);
+///
+/// // the outer brackets are not added to the result
+/// let v = place![[1, 2]];
+/// assert_eq!(v, [1, 2]);
+/// let v = place!((1, 2));
+/// assert_eq!(v, (1, 2));
+/// let v = place! {{ 5 }};
+/// assert_eq!(v, 5);
+/// ```
+///
+/// Expressions from macro metavariables keep their precedence. Rust ignores
+/// the invisible groups around metavariables once they pass through proc
+/// macro, so `place` replaces invisible groups that contain expression with
+/// binary operator with parenthesis. Other metavariables (types, patterns,
+/// items, ...) are kept without the parenthesis.
+/// ```
+/// use place_macro::place;
+///
+/// macro_rules! times_three {
+///     ($e:expr) => {
+///         place!($e * 3)
+///     };
+/// }
+/// assert_eq!(times_three!(1 + 2), 9);
+///
+/// macro_rules! len_minus_one {
+///     ($e:expr) => {
+///         place!(__identity__($e) - 1)
+///     };
+/// }
+/// assert_eq!(len_minus_one!([1, 2].len()), 1);
+///
+/// macro_rules! not {
+///     ($e:expr) => {
+///         place!(!__identity__($e))
+///     };
+/// }
+/// assert!(not!(1 == 2));
+///
+/// macro_rules! new_vec {
+///     ($t:ty) => {
+///         place!(Vec::<$t>::new())
+///     };
+/// }
+/// let v: Vec<Vec<u8>> = new_vec!(Vec<u8>);
+/// assert!(v.is_empty());
+/// ```
+///
+/// `__once__` fails when the same key is used twice in one `place`:
+/// ```
+/// use place_macro::place;
+///
+/// place! {
+///     __once__(foo) fn foo() {}
+///     __once__(bar) fn bar() {}
+/// }
+/// ```
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// place! {
+///     __once__(foo) fn foo() {}
+///     __once__(foo) fn bar() {}
+/// }
+/// ```
+///
+/// `__set__` stores tokens after expansion and `__ref__` pastes them:
+/// ```
+/// use place_macro::place;
+///
+/// let s = place! {
+///     __set__(name, (__string__(hello _ there)))
+///     [__ref__(name), __ref__(name)]
+/// };
+/// assert_eq!(s, ["hello_there", "hello_there"]);
+///
+/// let s = place! {
+///     __set__(s, (a))
+///     __set__(s, (__ref__(s) b))
+///     __string__(__ref__(s) c)
+/// };
+/// assert_eq!(s, "abc");
+/// ```
+///
+/// Name that was not set is error:
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// let n = place!(__ref__(n) __set__(n, (5)));
+/// ```
+///
+/// `__ignore__` can be used without parenthesis before other macro:
+/// ```
+/// use place_macro::place;
+///
+/// // ignores the group
+/// let n = place!(5 __ignore__(+ 3));
+/// assert_eq!(n, 5);
+///
+/// // ignores the dollar
+/// let n = place!(5 __ignore__ __dollar__);
+/// assert_eq!(n, 5);
+///
+/// // ignores only the outer `__string__`, the inner is still expanded
+/// let s = place!(__ignore__ __string__(__string__(a b)));
+/// assert_eq!(s, "ab");
+///
+/// // ignores the macro name so that the tail is not taken
+/// let n = place!(__ignore__ __tail__(- 5));
+/// assert_eq!(n, -5);
+/// ```
+///
+/// `__ignore__` followed by anything else is an error:
+/// ```compile_fail
+/// use place_macro::place;
+///
+/// let n = place!(__ignore__ 5);
+/// ```
+///
+/// `__dollar__` doesn't need anything after it, so it may be the last token:
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(foo __dollar__)), "foo $");
+/// assert_eq!(place!(__stringify__((foo __dollar__) __dollar__)), "(foo $) $");
+///
+/// macro_rules! dollar {
+///     () => {
+///         place!(__stringify__(__dollar__))
+///     };
+/// }
+/// assert_eq!(dollar!(), "$");
+/// ```
+///
+/// The name of `to_case` determines the case:
+/// ```
+/// use place_macro::place;
+///
+/// assert_eq!(place!(__stringify__(__TOCASE__(my_name))), "MYNAME");
+/// assert_eq!(place!(__stringify__(__tocase__(my_name))), "myname");
+/// assert_eq!(place!(__stringify__(__toCase__(my_name))), "myName");
+/// assert_eq!(place!(__stringify__(__ToCase__(my_name))), "MyName");
+/// assert_eq!(place!(__stringify__(__to_case__(MyName))), "my_name");
+/// assert_eq!(place!(__stringify__(__TO_CASE__(my_name))), "MY_NAME");
+/// assert_eq!(place!(__stringify__(__tOcAsE__(my_name))), "mYnAmE");
+///
+/// // other spellings are not macros
+/// let __Tocase__ = 5;
+/// assert_eq!(place!(__Tocase__), 5);
 /// ```
 pub use place_macro_proc::place;